@@ -0,0 +1,124 @@
+use std::fs;
+use std::io;
+use std::error::Error;
+use std::path::Path;
+use std::collections::BTreeMap;
+
+// Accepted answers, one per line as `day<TAB>part<TAB>input<TAB>answer`.
+// Backslashes and newlines in answers are escaped so multi-line answers
+// stay on one line; lines starting with `#` are comments.
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    entries: BTreeMap<Key, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Key {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+}
+
+impl Answers {
+    // A missing file is the same as an empty one
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        match fs::read_to_string(path) {
+            Ok(text) => Answers::parse(&text)
+                .map_err(|e| format!("{}:{}", path.display(), e).into()),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(format!("Could not read {}: {}", path.display(), e).into()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    fn parse(text: &str) -> Result<Self, String> {
+        let mut entries = BTreeMap::new();
+        for (idx, line) in text.lines().enumerate() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = line.splitn(4, '\t').collect();
+            let [day, part, input, answer] = fields[..] else {
+                return Err(format!("{}: expected 4 tab separated fields", idx + 1));
+            };
+            let key = Key {
+                day: day.parse().map_err(|_| format!("{}: invalid day `{}`", idx + 1, day))?,
+                part: part.parse().map_err(|_| format!("{}: invalid part `{}`", idx + 1, part))?,
+                input: input.to_string(),
+            };
+            entries.insert(key, unescape(answer));
+        }
+        Ok(Answers { entries })
+    }
+
+    pub fn insert(&mut self, key: Key, answer: String) -> Option<String> {
+        self.entries.insert(key, answer)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Key, &str)> {
+        self.entries.iter().map(|(key, answer)| (key, answer.as_str()))
+    }
+}
+
+impl std::fmt::Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# day\tpart\tinput\tanswer")?;
+        for (key, answer) in self.entries.iter() {
+            writeln!(f, "{}\t{}\t{}\t{}", key.day, key.part, key.input, escape(answer))?;
+        }
+        Ok(())
+    }
+}
+
+fn escape(answer: &str) -> String {
+    let mut escaped = String::new();
+    for c in answer.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn unescape(answer: &str) -> String {
+    let mut unescaped = String::new();
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => unescaped.push('\n'),
+            ('\\', Some('t')) => unescaped.push('\t'),
+            ('\\', Some('\\')) => unescaped.push('\\'),
+            (c, _) => {
+                unescaped.push(c);
+                continue;
+            }
+        }
+        chars.next();
+    }
+    unescaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut answers = Answers::default();
+        let key = Key { day: 10, part: 2, input: "day10/input.txt".to_string() };
+        answers.insert(key.clone(), "#..#\n\\..#\n".to_string());
+        answers.insert(Key { day: 1, part: 1, input: "day1/input.txt".to_string() }, "68923".to_string());
+
+        let text = answers.to_string();
+        assert_eq!(text.lines().count(), 3);
+        let parsed = Answers::parse(&text).unwrap();
+        assert_eq!(parsed, answers);
+        assert_eq!(parsed.entries[&key], "#..#\n\\..#\n");
+    }
+}
@@ -0,0 +1,145 @@
+use std::time::{Duration, Instant};
+use std::hint::black_box;
+use std::process::Command;
+use serde_json::{json, Value};
+use common::{ParseError, Solution};
+
+// Time taken by every iteration of each phase of a solution
+#[derive(Debug, Default)]
+pub struct Timings {
+    pub parse: Vec<Duration>,
+    pub part1: Vec<Duration>,
+    pub part2: Vec<Duration>,
+}
+
+impl Timings {
+    pub fn phases(&self) -> [(&'static str, &[Duration]); 3] {
+        [("parse", &self.parse), ("part1", &self.part1), ("part2", &self.part2)]
+    }
+}
+
+pub fn bench<S: Solution>(text: &str, iterations: usize) -> Result<Timings, ParseError> {
+    let mut timings = Timings::default();
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let input = black_box(S::parse(black_box(text))?);
+        timings.parse.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part1(&input));
+        timings.part1.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part2(&input));
+        timings.part2.push(start.elapsed());
+    }
+    Ok(timings)
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Stats {
+    pub mean: Duration,
+    pub median: Duration,
+    pub stddev: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty());
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n/2 - 1] + sorted[n/2]) / 2
+        } else {
+            sorted[n/2]
+        };
+
+        let nanos: Vec<f64> = sorted.iter().map(|d| d.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n as f64;
+
+        Stats {
+            mean: Duration::from_nanos(mean.round() as u64),
+            median,
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+            min: sorted[0],
+            max: sorted[n - 1],
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "mean_ns": self.mean.as_nanos() as u64,
+            "median_ns": self.median.as_nanos() as u64,
+            "stddev_ns": self.stddev.as_nanos() as u64,
+            "min_ns": self.min.as_nanos() as u64,
+            "max_ns": self.max.as_nanos() as u64,
+        })
+    }
+}
+
+// Summary table of one day's timings
+pub fn print_timings(day: u8, timings: &Timings) {
+    for (phase, samples) in timings.phases() {
+        let stats = Stats::new(samples);
+        println!(
+            "Day {:>2} {}: mean {:>10.2?}  median {:>10.2?}  stddev {:>10.2?}  min {:>10.2?}  max {:>10.2?}",
+            day, phase, stats.mean, stats.median, stats.stddev, stats.min, stats.max
+        );
+    }
+}
+
+pub fn day_json(day: u8, timings: &Timings) -> Value {
+    let mut phases = serde_json::Map::new();
+    for (phase, samples) in timings.phases() {
+        let mut stats = Stats::new(samples).to_json();
+        stats["samples"] = json!(samples.len());
+        phases.insert(phase.to_string(), stats);
+    }
+    json!({ "day": day, "phases": phases })
+}
+
+// Everything needed to compare a run against one from another commit
+pub fn report_json(iterations: usize, days: Vec<Value>) -> Value {
+    json!({
+        "iterations": iterations,
+        "commit": current_commit(),
+        "days": days,
+    })
+}
+
+// Benchmarks can run outside a checkout, so a missing commit is just null
+fn current_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let commit = String::from_utf8(output.stdout).ok()?;
+    Some(commit.trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let samples: Vec<Duration> = [4, 2, 9, 5, 5, 7, 4, 4]
+            .into_iter()
+            .map(Duration::from_micros)
+            .collect();
+        let stats = Stats::new(&samples);
+        assert_eq!(stats.mean, Duration::from_micros(5));
+        assert_eq!(stats.median, Duration::from_micros(9) / 2);
+        assert_eq!(stats.stddev, Duration::from_micros(2));
+        assert_eq!(stats.min, Duration::from_micros(2));
+        assert_eq!(stats.max, Duration::from_micros(9));
+    }
+}
@@ -0,0 +1,75 @@
+use std::io;
+use common::{ParseError, Solution};
+use common::visual::{Visualize, Visualizer};
+use crate::bench::{self, Timings};
+
+// A day's solution as the runner sees it
+pub struct Day {
+    pub number: u8,
+    // Puzzle input checked in next to the day's crate
+    pub input: &'static str,
+    pub solve: fn(&str, u8) -> Result<String, ParseError>,
+    pub bench: fn(&str, usize) -> Result<Timings, ParseError>,
+    // Only for days that simulate something
+    pub visualize: Option<VisualizeFn>,
+}
+
+// Fails with a ParseError before anything is shown, or with an io::Error
+// while showing it
+pub type VisualizeFn = fn(&str, u8, &mut Visualizer) -> Result<io::Result<()>, ParseError>;
+
+impl Day {
+    const fn new<S: Solution>(number: u8, input: &'static str) -> Self {
+        Day {
+            number,
+            input,
+            solve: solve::<S>,
+            bench: bench::bench::<S>,
+            visualize: None,
+        }
+    }
+
+    const fn visual<S: Visualize>(mut self) -> Self {
+        self.visualize = Some(visualize::<S>);
+        self
+    }
+}
+
+pub const DAYS: [Day; 18] = [
+    Day::new::<day1::Day1>(1, "day1/input.txt"),
+    Day::new::<day2::Day2>(2, "day2/input.txt"),
+    Day::new::<day3::Day3>(3, "day3/input.txt"),
+    Day::new::<day4::Day4>(4, "day4/input.txt"),
+    Day::new::<day5::Day5>(5, "day5/input.txt"),
+    Day::new::<day6::Day6>(6, "day6/input.txt"),
+    Day::new::<day7::Day7>(7, "day7/input.txt"),
+    Day::new::<day8::Day8>(8, "day8/input.txt"),
+    Day::new::<day9::Day9>(9, "day9/input.txt").visual::<day9::Day9>(),
+    Day::new::<day10::Day10>(10, "day10/input.txt"),
+    Day::new::<day11::Day11>(11, "day11/input.txt"),
+    Day::new::<day12::Day12>(12, "day12/data/input.txt"),
+    Day::new::<day13::Day13>(13, "day13/input.txt"),
+    Day::new::<day14::Day14>(14, "day14/input.txt").visual::<day14::Day14>(),
+    Day::new::<day15::Day15>(15, "day15/input.txt"),
+    Day::new::<day17::Day17>(17, "day17/input.txt").visual::<day17::Day17>(),
+    Day::new::<day18::Day18>(18, "day18/input.txt"),
+    Day::new::<day20::Day20>(20, "day20/input.txt"),
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+fn solve<S: Solution>(text: &str, part: u8) -> Result<String, ParseError> {
+    let input = S::parse(text)?;
+    Ok(match part {
+        1 => S::part1(&input).to_string(),
+        2 => S::part2(&input).to_string(),
+        _ => panic!("There is no part {}", part),
+    })
+}
+
+fn visualize<S: Visualize>(text: &str, part: u8, visualizer: &mut Visualizer) -> Result<io::Result<()>, ParseError> {
+    let input = S::parse(text)?;
+    Ok(S::visualize(&input, part, visualizer))
+}
@@ -0,0 +1,58 @@
+// Runs every day against the worked examples from the puzzle text, which
+// are recorded in examples.txt in the same format as answers.txt
+use std::fs;
+use std::path::{Path, PathBuf};
+use crate::answers::Answers;
+use crate::days;
+
+fn root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+fn check(number: u8) {
+    let examples = Answers::load(&root().join("examples.txt")).unwrap();
+    let day = days::find(number).unwrap();
+
+    let mut checked = 0;
+    for (key, expected) in examples.iter().filter(|(key, _)| key.day == number) {
+        let text = fs::read_to_string(root().join(&key.input)).unwrap();
+        let answer = (day.solve)(&text, key.part)
+            .unwrap_or_else(|e| panic!("{}", e.with_file(Path::new(&key.input))));
+        assert_eq!(answer, expected, "day {} part {} ({})", key.day, key.part, key.input);
+        checked += 1;
+    }
+    assert!(checked > 0, "examples.txt has nothing for day {}", number);
+}
+
+// One test per day so a failure names the day that broke
+macro_rules! examples {
+    ($($name:ident: $day:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                check($day);
+            }
+        )*
+    };
+}
+
+examples! {
+    day1: 1,
+    day2: 2,
+    day3: 3,
+    day4: 4,
+    day5: 5,
+    day6: 6,
+    day7: 7,
+    day8: 8,
+    day9: 9,
+    day10: 10,
+    day11: 11,
+    day12: 12,
+    day13: 13,
+    day14: 14,
+    day15: 15,
+    day17: 17,
+    day18: 18,
+    day20: 20,
+}
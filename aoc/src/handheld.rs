@@ -0,0 +1,84 @@
+// Tools for debugging programs for day 10's handheld device
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use clap::ValueEnum;
+use serde_json::{json, Value};
+use day10::{Instruction, InstructionSet, Schedule, Signal, Step};
+use crate::inputs::Inputs;
+use crate::{find_day, read_input};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum TraceFormat {
+    Csv,
+    Json,
+}
+
+// Read and assemble a program for the standard instruction set, from
+// day 10's input if no file is given
+fn assemble(inputs: &Inputs, input: Option<PathBuf>) -> Result<Vec<(usize, Instruction)>, Box<dyn Error>> {
+    let path = match input {
+        Some(path) => path,
+        None => inputs.resolve(find_day(10)?)?,
+    };
+    let text = read_input(&path)?;
+    Ok(InstructionSet::standard().assemble(&text).map_err(|e| e.with_file(&path))?)
+}
+
+pub fn signal(inputs: &Inputs, input: Option<PathBuf>, schedule: &Schedule) -> Result<(), Box<dyn Error>> {
+    let program = assemble(inputs, input)?;
+    let instructions: Vec<_> = program.into_iter().map(|(_, instruction)| instruction).collect();
+    let signal = Signal::new(&InstructionSet::standard(), &instructions);
+
+    let mut sum = 0;
+    for (cycle, strength) in signal.sample(schedule) {
+        match strength {
+            Some(strength) => {
+                println!("cycle {}: {}", cycle, strength);
+                sum += strength;
+            }
+            None => println!("cycle {}: program ended after {} cycles", cycle, signal.cycles()),
+        }
+    }
+    println!("sum: {}", sum);
+    Ok(())
+}
+
+pub fn disassemble(inputs: &Inputs, input: Option<PathBuf>, lines: bool) -> Result<(), Box<dyn Error>> {
+    let program = assemble(inputs, input)?;
+    if lines {
+        print!("{}", day10::listing(&program));
+    } else {
+        let instructions: Vec<_> = program.into_iter().map(|(_, instruction)| instruction).collect();
+        print!("{}", day10::disassemble(&instructions));
+    }
+    Ok(())
+}
+
+pub fn trace(inputs: &Inputs, input: Option<PathBuf>, format: TraceFormat, output: Option<&Path>) -> Result<(), Box<dyn Error>> {
+    let program = assemble(inputs, input)?;
+    let steps = day10::trace(&InstructionSet::standard(), &program);
+    let text = match format {
+        TraceFormat::Csv => day10::to_csv(&steps),
+        TraceFormat::Json => format!("{:#}\n", Value::from_iter(steps.iter().map(step_json))),
+    };
+    match output {
+        Some(path) => fs::write(path, text)
+            .map_err(|e| format!("Could not write {}: {}", path.display(), e).into()),
+        None => {
+            print!("{}", text);
+            Ok(())
+        }
+    }
+}
+
+fn step_json(step: &Step) -> Value {
+    json!({
+        "cycle": step.cycle,
+        "line": step.line,
+        "instruction": step.instruction,
+        "x_before": step.x_before,
+        "x_after": step.x_after,
+        "pixel": step.pixel.map(|((x, y), lit)| json!({ "x": x, "y": y, "lit": lit })),
+    })
+}
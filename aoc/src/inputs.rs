@@ -0,0 +1,234 @@
+use std::fs;
+use std::fmt;
+use std::io;
+use std::error::Error;
+use std::cell::Cell;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use crate::days::Day;
+
+pub const YEAR: u16 = 2022;
+pub const DEFAULT_URL: &str = "https://adventofcode.com";
+// Shortest gap between two downloads
+pub const FETCH_INTERVAL: Duration = Duration::from_secs(3);
+
+// Somewhere puzzle inputs can be downloaded from
+pub trait Fetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, FetchError>;
+}
+
+#[derive(Debug)]
+pub enum FetchError {
+    // The server answered, but not with an input
+    Status { url: String, status: u16, body: String },
+    // The server couldn't be reached at all
+    Transport { url: String, message: String },
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::Status { url, status: 404, .. } => {
+                write!(f, "{} returned 404, the puzzle may not be unlocked yet", url)
+            }
+            FetchError::Status { url, status: status @ (400 | 401 | 403), .. } => {
+                write!(f, "{} returned {}, check that the session token is valid", url, status)
+            }
+            FetchError::Status { url, status, body } => {
+                write!(f, "{} returned {}: {}", url, status, body.trim())
+            }
+            FetchError::Transport { url, message } => write!(f, "Could not reach {}: {}", url, message),
+        }
+    }
+}
+
+impl Error for FetchError {}
+
+// Downloads inputs over HTTP with the user's session cookie. The base URL
+// can point at a local server so this can be tested without the real site.
+pub struct HttpFetcher {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+    // The site asks for requests to be spread out, so keep at least this
+    // much time between two of them
+    min_interval: Duration,
+    last_request: Cell<Option<Instant>>,
+}
+
+impl HttpFetcher {
+    pub fn new(base_url: &str, session: &str, min_interval: Duration) -> Self {
+        HttpFetcher {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(concat!("aoc-runner/", env!("CARGO_PKG_VERSION")))
+                .build(),
+            min_interval,
+            last_request: Cell::new(None),
+        }
+    }
+
+    fn wait_for_turn(&self) {
+        if let Some(last) = self.last_request.get() {
+            let elapsed = last.elapsed();
+            if elapsed < self.min_interval {
+                std::thread::sleep(self.min_interval - elapsed);
+            }
+        }
+        self.last_request.set(Some(Instant::now()));
+    }
+}
+
+impl Fetcher for HttpFetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        self.wait_for_turn();
+        let response = self.agent.get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+        match response {
+            Ok(response) => response.into_string().map_err(|e| FetchError::Transport {
+                url: url.clone(),
+                message: e.to_string(),
+            }),
+            Err(ureq::Error::Status(status, response)) => Err(FetchError::Status {
+                url: url.clone(),
+                status,
+                body: response.into_string().unwrap_or_default(),
+            }),
+            Err(ureq::Error::Transport(e)) => Err(FetchError::Transport {
+                url: url.clone(),
+                message: match e.message() {
+                    Some(message) => format!("{}: {}", e.kind(), message),
+                    None => e.kind().to_string(),
+                },
+            }),
+        }
+    }
+}
+
+// Finds the input file for a day. Inputs checked in next to a day's crate
+// win, then the cache directory (`<cache>/<year>/day<N>.txt`), and only
+// when neither exists is the input fetched and saved to the cache.
+pub struct Inputs {
+    cache_dir: PathBuf,
+    year: u16,
+    fetcher: Option<Box<dyn Fetcher>>,
+}
+
+impl Inputs {
+    pub fn new(cache_dir: &Path, year: u16, fetcher: Option<Box<dyn Fetcher>>) -> Self {
+        Inputs {
+            cache_dir: cache_dir.to_path_buf(),
+            year,
+            fetcher,
+        }
+    }
+
+    pub fn cache_path(&self, day: u8) -> PathBuf {
+        self.cache_dir.join(self.year.to_string()).join(format!("day{}.txt", day))
+    }
+
+    pub fn resolve(&self, day: &Day) -> Result<PathBuf, Box<dyn Error>> {
+        let checked_in = Path::new(day.input);
+        if checked_in.exists() {
+            return Ok(checked_in.to_path_buf());
+        }
+        let cached = self.cache_path(day.number);
+        if cached.exists() {
+            return Ok(cached);
+        }
+        self.fetch(day.number).map_err(|e| {
+            format!(
+                "No input for day {} ({} and {} don't exist): {}",
+                day.number, checked_in.display(), cached.display(), e
+            ).into()
+        })
+    }
+
+    // Download a day's input into the cache, replacing any cached copy
+    pub fn fetch(&self, day: u8) -> Result<PathBuf, Box<dyn Error>> {
+        let Some(fetcher) = &self.fetcher else {
+            return Err("No session token is set to download it with (AOC_SESSION)".into());
+        };
+        let text = fetcher.fetch(self.year, day)?;
+        let path = self.cache_path(day);
+        write_file(&path, &text)
+            .map_err(|e| format!("Could not write {}: {}", path.display(), e))?;
+        Ok(path)
+    }
+}
+
+fn write_file(path: &Path, text: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    // Answer each request with the path that was asked for, or 404 for day 25
+    fn serve(requests: usize) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let mut cookies = Vec::new();
+            for stream in listener.incoming().take(requests) {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request = String::new();
+                reader.read_line(&mut request).unwrap();
+                let path = request.split_whitespace().nth(1).unwrap().to_string();
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header.trim().is_empty() {
+                        break;
+                    }
+                    if let Some(cookie) = header.strip_prefix("cookie: ").or(header.strip_prefix("Cookie: ")) {
+                        cookies.push(cookie.trim().to_string());
+                    }
+                }
+                let (status, body) = match path.as_str() {
+                    "/2022/day/25/input" => ("404 Not Found", "Not found".to_string()),
+                    _ => ("200 OK", format!("{}\n", path)),
+                };
+                write!(
+                    stream,
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status, body.len(), body
+                ).unwrap();
+            }
+            cookies
+        });
+        (url, handle)
+    }
+
+    #[test]
+    fn fetch_into_cache() {
+        let (url, server) = serve(2);
+        let cache = std::env::temp_dir().join(format!("aoc-inputs-test-{}", std::process::id()));
+        let fetcher = HttpFetcher::new(&url, "abc123", Duration::from_millis(200));
+        let inputs = Inputs::new(&cache, YEAR, Some(Box::new(fetcher)));
+
+        let start = Instant::now();
+        let path = inputs.fetch(3).unwrap();
+        assert_eq!(path, cache.join("2022").join("day3.txt"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "/2022/day/3/input\n");
+
+        let err = inputs.fetch(25).unwrap_err();
+        assert!(err.to_string().contains("may not be unlocked yet"), "{}", err);
+        assert!(start.elapsed() >= Duration::from_millis(200));
+
+        assert_eq!(server.join().unwrap(), ["session=abc123", "session=abc123"]);
+        fs::remove_dir_all(&cache).unwrap();
+    }
+}
@@ -0,0 +1,381 @@
+use std::fs;
+use std::panic;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+use clap::{Parser, Subcommand};
+use common::ParseError;
+use common::visual::Visualizer;
+use day10::Schedule;
+use answers::{Answers, Key};
+use handheld::TraceFormat;
+use inputs::{HttpFetcher, Inputs};
+
+mod answers;
+mod bench;
+mod handheld;
+mod days;
+mod inputs;
+#[cfg(test)]
+mod examples;
+
+#[derive(Parser)]
+#[command(about = "Run the Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// File of accepted answers
+    #[arg(long, global = true, default_value = "answers.txt")]
+    answers: PathBuf,
+    /// Directory downloaded inputs are cached in
+    #[arg(long, global = true, env = "AOC_CACHE_DIR", default_value = ".cache")]
+    cache_dir: PathBuf,
+    /// Site to download inputs from
+    #[arg(long, global = true, env = "AOC_BASE_URL", default_value = inputs::DEFAULT_URL)]
+    base_url: String,
+    /// Session cookie used to download inputs
+    #[arg(long, global = true, env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solution for a day, or for every day if none is given
+    Run {
+        day: Option<u8>,
+        /// Only run this part (1 or 2)
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file to use instead of the day's default input
+        #[arg(long, requires = "day")]
+        input: Option<PathBuf>,
+        /// Save the answers as accepted answers for this input
+        #[arg(long)]
+        record: bool,
+    },
+    /// Check the solutions against every accepted answer
+    Verify {
+        /// Only check this day
+        day: Option<u8>,
+    },
+    /// Download a day's input into the cache, or every day's missing inputs if none is given
+    Fetch {
+        day: Option<u8>,
+    },
+    /// Animate a day's simulation in the terminal, or record its frames to a file
+    Visualize {
+        day: u8,
+        /// Part whose simulation to show (1 or 2)
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Input file to use instead of the day's default input
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Frames per second, as fast as possible if not given
+        #[arg(long)]
+        fps: Option<f64>,
+        /// Only show every Nth frame
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+        every: u64,
+        /// Start paused and advance a frame each time Enter is pressed
+        #[arg(long)]
+        step: bool,
+        /// Write the frames to this file instead of animating them
+        #[arg(long, conflicts_with_all = ["fps", "step"])]
+        output: Option<PathBuf>,
+    },
+    /// Print day 10's signal strength at chosen cycles
+    Signal {
+        /// Input file to use instead of day 10's default input
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Cycles to sample, counting from 1, separated by commas
+        #[arg(long, value_delimiter = ',', conflicts_with_all = ["start", "step"], value_parser = clap::value_parser!(u32).range(1..))]
+        cycles: Vec<u32>,
+        /// First cycle to sample, counting from 1
+        #[arg(long, default_value_t = 20, value_parser = clap::value_parser!(u32).range(1..))]
+        start: u32,
+        /// Cycles between samples, which go on until the program ends
+        #[arg(long, default_value_t = 40, value_parser = clap::value_parser!(u32).range(1..))]
+        step: u32,
+    },
+    /// Print day 10's program back out the way it was read
+    Disassemble {
+        /// Input file to use instead of day 10's default input
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Put the line each instruction was read from in front of it
+        #[arg(long)]
+        lines: bool,
+    },
+    /// Write what day 10's CPU and CRT do during every cycle
+    Trace {
+        /// Input file to use instead of day 10's default input
+        #[arg(long)]
+        input: Option<PathBuf>,
+        #[arg(long, value_enum, default_value_t = TraceFormat::Csv)]
+        format: TraceFormat,
+        /// Write the trace to this file instead of printing it
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Time parsing and both parts of a day, or of every day if none is given
+    Bench {
+        day: Option<u8>,
+        /// Number of times to run each phase
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        iterations: u64,
+        /// Also write the results as JSON to this file
+        #[arg(long)]
+        json: Option<PathBuf>,
+    },
+}
+
+// Why a part didn't produce an answer
+enum Failure {
+    Parse(ParseError),
+    Panicked,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let fetcher = cli.session.as_deref().map(|session| {
+        Box::new(HttpFetcher::new(&cli.base_url, session, inputs::FETCH_INTERVAL)) as _
+    });
+    let inputs = Inputs::new(&cli.cache_dir, inputs::YEAR, fetcher);
+
+    let result = match cli.command {
+        Command::Run { day, part, input, record } => {
+            run_days(&inputs, day, part, input, record.then_some(cli.answers.as_path()))
+        }
+        Command::Verify { day } => verify(day, &cli.answers),
+        Command::Fetch { day } => fetch(&inputs, day),
+        Command::Visualize { day, part, input, fps, every, step, output } => {
+            let every = every as usize;
+            let visualizer = match output {
+                Some(path) => Visualizer::record(&path, every)
+                    .map_err(|e| format!("Could not create {}: {}", path.display(), e).into()),
+                None => Ok(Visualizer::terminal(fps, every, step)),
+            };
+            visualizer.and_then(|mut visualizer| visualize(&inputs, day, part, input, &mut visualizer))
+        }
+        Command::Signal { input, cycles, start, step } => {
+            let schedule = if cycles.is_empty() {
+                Schedule::Every { start, step }
+            } else {
+                Schedule::Cycles(cycles)
+            };
+            handheld::signal(&inputs, input, &schedule)
+        }
+        Command::Disassemble { input, lines } => handheld::disassemble(&inputs, input, lines),
+        Command::Trace { input, format, output } => handheld::trace(&inputs, input, format, output.as_deref()),
+        Command::Bench { day, iterations, json } => {
+            bench_days(&inputs, day, iterations as usize, json.as_deref())
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run_days(inputs: &Inputs, day: Option<u8>, part: Option<u8>, input: Option<PathBuf>, record: Option<&Path>) -> Result<(), Box<dyn Error>> {
+    let days = match day {
+        Some(day) => vec![find_day(day)?],
+        None => days::DAYS.iter().collect(),
+    };
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let mut answers = match record {
+        Some(path) => Answers::load(path)?,
+        None => Answers::default(),
+    };
+
+    let mut failed = 0;
+    for day in days {
+        let path = match &input {
+            Some(path) => path.clone(),
+            None => inputs.resolve(day)?,
+        };
+        let text = read_input(&path)?;
+        for &part in parts.iter() {
+            match solve(day, part, &text) {
+                Ok((answer, elapsed)) => {
+                    print_answer(day.number, part, &answer, elapsed);
+                    let key = Key { day: day.number, part, input: path.display().to_string() };
+                    answers.insert(key, answer);
+                }
+                Err(Failure::Panicked) => println!("Day {} part {}: panicked", day.number, part),
+                // Both parts share the parser, so one report is enough
+                Err(Failure::Parse(e)) => {
+                    eprintln!("error: {}", e.with_file(&path));
+                    failed += 1;
+                    break;
+                }
+            }
+        }
+    }
+
+    if let Some(path) = record {
+        answers.save(path)?;
+    }
+
+    match failed {
+        0 => Ok(()),
+        n => Err(format!("{} day(s) could not parse their input", n).into()),
+    }
+}
+
+fn verify(day: Option<u8>, answers_path: &Path) -> Result<(), Box<dyn Error>> {
+    let answers = Answers::load(answers_path)?;
+    let (mut passed, mut changed, mut failed) = (0, 0, 0);
+
+    for (key, expected) in answers.iter() {
+        if day.is_some_and(|day| day != key.day) {
+            continue;
+        }
+        let label = format!("day {} part {} ({})", key.day, key.part, key.input);
+        let result = find_day(key.day).and_then(|day| {
+            let text = read_input(Path::new(&key.input))?;
+            Ok(solve(day, key.part, &text))
+        });
+        match result {
+            Ok(Ok((answer, _))) if answer == expected => {
+                println!("pass     {}", label);
+                passed += 1;
+            }
+            Ok(Ok((answer, _))) => {
+                if answer.contains('\n') || expected.contains('\n') {
+                    println!("changed  {}: expected\n{}\ngot\n{}", label, expected.trim_end(), answer.trim_end());
+                } else {
+                    println!("changed  {}: expected {}, got {}", label, expected, answer);
+                }
+                changed += 1;
+            }
+            Ok(Err(Failure::Parse(e))) => {
+                println!("fail     {}: {}", label, e.with_file(Path::new(&key.input)));
+                failed += 1;
+            }
+            Ok(Err(Failure::Panicked)) => {
+                println!("fail     {}: panicked", label);
+                failed += 1;
+            }
+            Err(e) => {
+                println!("fail     {}: {}", label, e);
+                failed += 1;
+            }
+        }
+    }
+
+    println!("{} passed, {} changed, {} failed", passed, changed, failed);
+    match changed + failed {
+        0 => Ok(()),
+        _ => Err("Some answers no longer match".into()),
+    }
+}
+
+fn visualize(inputs: &Inputs, day: u8, part: u8, input: Option<PathBuf>, visualizer: &mut Visualizer) -> Result<(), Box<dyn Error>> {
+    let day = find_day(day)?;
+    let visualize = day.visualize
+        .ok_or_else(|| format!("Day {} has no simulation to visualize", day.number))?;
+    let path = match input {
+        Some(path) => path,
+        None => inputs.resolve(day)?,
+    };
+    let text = read_input(&path)?;
+    match visualize(&text, part, visualizer) {
+        Ok(result) => Ok(result?),
+        Err(e) => Err(e.with_file(&path).into()),
+    }
+}
+
+fn fetch(inputs: &Inputs, day: Option<u8>) -> Result<(), Box<dyn Error>> {
+    match day {
+        Some(day) => {
+            let path = inputs.fetch(find_day(day)?.number)?;
+            println!("Day {}: saved {}", day, path.display());
+        }
+        None => {
+            for day in days::DAYS.iter() {
+                let path = inputs.resolve(day)?;
+                println!("Day {}: {}", day.number, path.display());
+            }
+        }
+    }
+    Ok(())
+}
+
+fn bench_days(inputs: &Inputs, day: Option<u8>, iterations: usize, json: Option<&Path>) -> Result<(), Box<dyn Error>> {
+    let days = match day {
+        Some(day) => vec![find_day(day)?],
+        None => days::DAYS.iter().collect(),
+    };
+
+    let mut results = Vec::new();
+    let mut failed = 0;
+    for day in days {
+        let path = inputs.resolve(day)?;
+        let text = read_input(&path)?;
+        match panic::catch_unwind(|| (day.bench)(&text, iterations)) {
+            Ok(Ok(timings)) => {
+                bench::print_timings(day.number, &timings);
+                results.push(bench::day_json(day.number, &timings));
+            }
+            Ok(Err(e)) => {
+                eprintln!("error: {}", e.with_file(&path));
+                failed += 1;
+            }
+            // Days that panic are left out of the report rather than stopping it
+            Err(_) => println!("Day {:>2}: panicked", day.number),
+        }
+    }
+
+    if let Some(path) = json {
+        let report = bench::report_json(iterations, results);
+        fs::write(path, format!("{:#}\n", report))
+            .map_err(|e| format!("Could not write {}: {}", path.display(), e))?;
+    }
+
+    match failed {
+        0 => Ok(()),
+        n => Err(format!("{} day(s) could not parse their input", n).into()),
+    }
+}
+
+fn find_day(number: u8) -> Result<&'static days::Day, Box<dyn Error>> {
+    days::find(number).ok_or_else(|| format!("No solution for day {}", number).into())
+}
+
+fn read_input(path: &Path) -> Result<String, Box<dyn Error>> {
+    fs::read_to_string(path)
+        .map_err(|e| format!("Could not read {}: {}", path.display(), e).into())
+}
+
+fn solve(day: &days::Day, part: u8, text: &str) -> Result<(String, Duration), Failure> {
+    let start = Instant::now();
+    // A panicking solution shouldn't stop the remaining days from running
+    let answer = panic::catch_unwind(|| (day.solve)(text, part));
+    let elapsed = start.elapsed();
+
+    match answer {
+        Ok(Ok(answer)) => Ok((answer, elapsed)),
+        Ok(Err(e)) => Err(Failure::Parse(e)),
+        Err(_) => Err(Failure::Panicked),
+    }
+}
+
+fn print_answer(day: u8, part: u8, answer: &str, elapsed: Duration) {
+    // Multi-line answers (e.g. rendered images) start on their own line
+    if answer.contains('\n') {
+        println!("Day {} part {} ({:.2?}):\n{}", day, part, elapsed, answer.trim_end());
+    } else {
+        println!("Day {} part {}: {} ({:.2?})", day, part, answer, elapsed);
+    }
+}
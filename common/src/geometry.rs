@@ -0,0 +1,293 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+// Signed integers that points can be built from
+pub trait Scalar:
+    Copy + Ord + fmt::Debug
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+}
+
+macro_rules! impl_scalar {
+    ($($t:ty),*) => {
+        $(
+            impl Scalar for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                fn signum(self) -> Self {
+                    <$t>::signum(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar!(i8, i16, i32, i64, i128, isize);
+
+// Everything points of any dimension have in common, written once for
+// each of them. A point and the offset between two points share a type.
+macro_rules! point {
+    ($point:ident, $vector:ident, $bounds:ident, $($c:ident),+) => {
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $point<T> {
+            $(pub $c: T),+
+        }
+
+        pub type $vector<T> = $point<T>;
+
+        impl<T: Scalar> $point<T> {
+            pub const fn new($($c: T),+) -> Self {
+                $point { $($c),+ }
+            }
+
+            pub fn zero() -> Self {
+                $point { $($c: T::ZERO),+ }
+            }
+
+            // Sum of the distances along each axis
+            pub fn manhattan(self) -> T {
+                T::ZERO $(+ self.$c.abs())+
+            }
+
+            // Largest distance along any axis
+            pub fn chebyshev(self) -> T {
+                [$(self.$c.abs()),+].into_iter().max().unwrap()
+            }
+
+            pub fn manhattan_distance(self, other: Self) -> T {
+                (self - other).manhattan()
+            }
+
+            pub fn chebyshev_distance(self, other: Self) -> T {
+                (self - other).chebyshev()
+            }
+
+            // Each component as -1, 0 or 1, i.e. one step in the direction
+            // of this vector along every axis it moves on
+            pub fn signum(self) -> Self {
+                $point { $($c: self.$c.signum()),+ }
+            }
+
+            pub fn min(self, other: Self) -> Self {
+                $point { $($c: self.$c.min(other.$c)),+ }
+            }
+
+            pub fn max(self, other: Self) -> Self {
+                $point { $($c: self.$c.max(other.$c)),+ }
+            }
+        }
+
+        impl<T: Scalar> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                $point { $($c: self.$c + other.$c),+ }
+            }
+        }
+
+        impl<T: Scalar> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                $point { $($c: self.$c - other.$c),+ }
+            }
+        }
+
+        impl<T: Scalar> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $point { $($c: -self.$c),+ }
+            }
+        }
+
+        impl<T: Scalar> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, factor: T) -> Self {
+                $point { $($c: self.$c * factor),+ }
+            }
+        }
+
+        impl<T: Scalar> Div<T> for $point<T> {
+            type Output = Self;
+
+            fn div(self, divisor: T) -> Self {
+                $point { $($c: self.$c / divisor),+ }
+            }
+        }
+
+        impl<T: Scalar> AddAssign for $point<T> {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl<T: Scalar> SubAssign for $point<T> {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        // Smallest box, edges included, holding a set of points
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub struct $bounds<T> {
+            pub min: $point<T>,
+            pub max: $point<T>,
+        }
+
+        impl<T: Scalar> $bounds<T> {
+            pub fn new(min: $point<T>, max: $point<T>) -> Self {
+                $bounds { min, max }
+            }
+
+            // None when there are no points
+            pub fn from_points(points: impl IntoIterator<Item = $point<T>>) -> Option<Self> {
+                let mut points = points.into_iter();
+                let first = points.next()?;
+                let mut bounds = $bounds::new(first, first);
+                for point in points {
+                    bounds.include(point);
+                }
+                Some(bounds)
+            }
+
+            // Grow to take in `point`
+            pub fn include(&mut self, point: $point<T>) {
+                self.min = self.min.min(point);
+                self.max = self.max.max(point);
+            }
+
+            pub fn contains(&self, point: $point<T>) -> bool {
+                $(self.min.$c <= point.$c && point.$c <= self.max.$c)&&+
+            }
+
+            // The same box with `margin` more room on every side
+            pub fn expand(&self, margin: T) -> Self {
+                let margin = $point { $($c: margin),+ };
+                $bounds::new(self.min - margin, self.max + margin)
+            }
+
+            // Number of points along each axis
+            pub fn size(&self) -> $point<T> {
+                $point { $($c: self.max.$c - self.min.$c + T::ONE),+ }
+            }
+        }
+    };
+}
+
+point!(Point2, Vector2, Bounds2, x, y);
+point!(Point3, Vector3, Bounds3, x, y, z);
+
+impl<T: Scalar> Point2<T> {
+    // Points sharing an edge
+    pub fn neighbours4(self) -> [Self; 4] {
+        let (zero, one) = (T::ZERO, T::ONE);
+        [
+            self + Point2::new(zero, -one),
+            self + Point2::new(one, zero),
+            self + Point2::new(zero, one),
+            self + Point2::new(-one, zero),
+        ]
+    }
+
+    // Points sharing an edge or a corner
+    pub fn neighbours8(self) -> [Self; 8] {
+        let (zero, one) = (T::ZERO, T::ONE);
+        [
+            self + Point2::new(zero, -one),
+            self + Point2::new(one, -one),
+            self + Point2::new(one, zero),
+            self + Point2::new(one, one),
+            self + Point2::new(zero, one),
+            self + Point2::new(-one, one),
+            self + Point2::new(-one, zero),
+            self + Point2::new(-one, -one),
+        ]
+    }
+}
+
+impl<T: Scalar> Bounds2<T> {
+    pub fn corners(&self) -> [Point2<T>; 4] {
+        [
+            self.min,
+            Point2::new(self.max.x, self.min.y),
+            Point2::new(self.min.x, self.max.y),
+            self.max,
+        ]
+    }
+}
+
+impl<T: Scalar> Point3<T> {
+    // Points sharing a face
+    pub fn neighbours6(self) -> [Self; 6] {
+        let (zero, one) = (T::ZERO, T::ONE);
+        [
+            self + Point3::new(one, zero, zero),
+            self + Point3::new(-one, zero, zero),
+            self + Point3::new(zero, one, zero),
+            self + Point3::new(zero, -one, zero),
+            self + Point3::new(zero, zero, one),
+            self + Point3::new(zero, zero, -one),
+        ]
+    }
+
+    // Points sharing a face, an edge or a corner
+    pub fn neighbours26(self) -> Vec<Self> {
+        let steps = [-T::ONE, T::ZERO, T::ONE];
+        let mut neighbours = Vec::with_capacity(26);
+        for x in steps {
+            for y in steps {
+                for z in steps {
+                    let step = Point3::new(x, y, z);
+                    if step != Point3::zero() {
+                        neighbours.push(self + step);
+                    }
+                }
+            }
+        }
+        neighbours
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn metrics() {
+        let a = Point2::new(1, -2);
+        let b = Point2::new(4, 5);
+        assert_eq!(a.manhattan_distance(b), 10);
+        assert_eq!(a.chebyshev_distance(b), 7);
+        assert_eq!((b - a).signum(), Vector2::new(1, 1));
+        assert_eq!(Point3::new(0i64, -3, 2).signum(), Point3::new(0, -1, 1));
+        assert_eq!(a * 2 + b, Point2::new(6, 1));
+    }
+
+    #[test]
+    fn neighbours_and_bounds() {
+        let origin = Point3::<i32>::zero();
+        assert!(origin.neighbours6().iter().all(|p| p.manhattan() == 1));
+        assert_eq!(origin.neighbours26().len(), 26);
+        assert!(Point2::new(3, 3).neighbours8().iter().all(|p| p.chebyshev_distance(Point2::new(3, 3)) == 1));
+
+        let bounds = Bounds2::from_points([Point2::new(2, 5), Point2::new(-1, 7), Point2::new(0, 6)]).unwrap();
+        assert_eq!(bounds, Bounds2::new(Point2::new(-1, 5), Point2::new(2, 7)));
+        assert_eq!(bounds.size(), Point2::new(4, 3));
+        assert!(bounds.contains(Point2::new(0, 5)));
+        assert!(!bounds.contains(Point2::new(0, 8)));
+        assert!(bounds.expand(1).contains(Point2::new(0, 8)));
+        assert_eq!(Bounds3::<i32>::from_points([]), None);
+    }
+}
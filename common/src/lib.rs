@@ -0,0 +1,20 @@
+use std::fmt::Display;
+
+pub mod geometry;
+pub mod parse;
+pub mod visual;
+
+pub use parse::ParseError;
+
+// Every day's puzzle is solved in the same three steps: the input is parsed
+// once and both parts are computed from the parsed form, so they can be run,
+// tested and timed separately.
+pub trait Solution {
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Answer1;
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
@@ -0,0 +1,202 @@
+use std::fmt;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::str::{FromStr, SplitWhitespace};
+
+// Where an input stopped making sense and what was expected there.
+// Lines and columns are 1-based, columns count characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    // None when the line or input ended early
+    pub found: Option<String>,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: &str, found: Option<&str>) -> Self {
+        ParseError {
+            file: None,
+            line,
+            column,
+            expected: expected.to_string(),
+            found: found.map(String::from),
+        }
+    }
+
+    // Parsers only see text, so the caller fills in where it came from
+    pub fn with_file(mut self, file: &Path) -> Self {
+        self.file = Some(file.to_path_buf());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:", file.display())?,
+            None => write!(f, "<input>:")?,
+        }
+        write!(f, "{}:{}: expected {}", self.line, self.column, self.expected)?;
+        match &self.found {
+            Some(found) => write!(f, ", found `{}`", found),
+            None => write!(f, ", found end of line"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+// Numbered lines of an input
+pub fn lines(text: &str) -> impl Iterator<Item = Line<'_>> {
+    text.lines()
+        .enumerate()
+        .map(|(idx, text)| Line { number: idx + 1, text })
+}
+
+// Error for input that ends before something required was found
+pub fn unexpected_end(text: &str, expected: &str) -> ParseError {
+    ParseError::new(text.lines().count() + 1, 1, expected, None)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    // Column of a slice taken out of this line
+    pub fn column_of(&self, part: &str) -> usize {
+        let offset = (part.as_ptr() as usize).saturating_sub(self.text.as_ptr() as usize);
+        let offset = offset.min(self.text.len());
+        self.text[..offset].chars().count() + 1
+    }
+
+    // Error pointing at a slice taken out of this line
+    pub fn error_at(&self, part: &str, expected: &str) -> ParseError {
+        ParseError::new(self.number, self.column_of(part), expected, Some(part))
+    }
+
+    // Error pointing just past the end of this line
+    pub fn error_at_end(&self, expected: &str) -> ParseError {
+        ParseError::new(self.number, self.text.chars().count() + 1, expected, None)
+    }
+
+    // Parse a slice taken out of this line
+    pub fn parse<T: FromStr>(&self, part: &str, expected: &str) -> Result<T, ParseError> {
+        part.parse::<T>().map_err(|_| self.error_at(part, expected))
+    }
+
+    // Make sure every character of this line is one that `valid` accepts
+    pub fn check_chars(&self, valid: impl Fn(char) -> bool, expected: &str) -> Result<(), ParseError> {
+        match self.text.char_indices().find(|(_, c)| !valid(*c)) {
+            Some((idx, c)) => Err(self.error_at(&self.text[idx..idx + c.len_utf8()], expected)),
+            None => Ok(()),
+        }
+    }
+
+    pub fn tokens(&self) -> Tokens<'a> {
+        Tokens {
+            line: *self,
+            iter: self.text.split_whitespace(),
+        }
+    }
+}
+
+// Whitespace separated tokens of a line that know where they came from
+pub struct Tokens<'a> {
+    line: Line<'a>,
+    iter: SplitWhitespace<'a>,
+}
+
+impl<'a> Tokens<'a> {
+    pub fn next_token(&mut self, expected: &str) -> Result<&'a str, ParseError> {
+        self.iter.next().ok_or_else(|| self.line.error_at_end(expected))
+    }
+
+    // Consume a token that has to be exactly `token`
+    pub fn expect(&mut self, token: &str) -> Result<(), ParseError> {
+        let expected = format!("`{}`", token);
+        match self.next_token(&expected)? {
+            t if t == token => Ok(()),
+            t => Err(self.line.error_at(t, &expected)),
+        }
+    }
+
+    pub fn parse<T: FromStr>(&mut self, expected: &str) -> Result<T, ParseError> {
+        let token = self.next_token(expected)?;
+        self.line.parse(token, expected)
+    }
+
+    // Make sure nothing is left on the line
+    pub fn end(&mut self) -> Result<(), ParseError> {
+        match self.iter.next() {
+            Some(token) => Err(self.line.error_at(token, "end of line")),
+            None => Ok(()),
+        }
+    }
+
+    // Everything left on the line from the next token on, for parts that
+    // aren't split by whitespace. Nothing is left afterwards.
+    pub fn rest(&mut self) -> Option<&'a str> {
+        let token = self.iter.next()?;
+        let start = token.as_ptr() as usize - self.line.text.as_ptr() as usize;
+        self.iter = self.line.text[self.line.text.len()..].split_whitespace();
+        Some(self.line.text[start..].trim_end())
+    }
+
+    pub fn line(&self) -> Line<'a> {
+        self.line
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_points_at_token() {
+        let line = lines("noop\naddx  x1").nth(1).unwrap();
+        let mut tokens = line.tokens();
+        tokens.expect("addx").unwrap();
+        let err = tokens.parse::<i32>("a number").unwrap_err();
+        assert_eq!(err, ParseError::new(2, 7, "a number", Some("x1")));
+        assert_eq!(
+            err.with_file(Path::new("day10/input.txt")).to_string(),
+            "day10/input.txt:2:7: expected a number, found `x1`"
+        );
+    }
+
+    #[test]
+    fn error_at_end_of_line() {
+        let line = lines("addx").next().unwrap();
+        let mut tokens = line.tokens();
+        tokens.next_token("an instruction").unwrap();
+        let err = tokens.parse::<i32>("a number").unwrap_err();
+        assert_eq!(err.to_string(), "<input>:1:5: expected a number, found end of line");
+    }
+
+    #[test]
+    fn rest_of_line() {
+        let line = lines("  new = (old + 1) * 2 ").next().unwrap();
+        let mut tokens = line.tokens();
+        tokens.expect("new").unwrap();
+        tokens.expect("=").unwrap();
+        let rest = tokens.rest().unwrap();
+        assert_eq!(rest, "(old + 1) * 2");
+        assert_eq!(line.column_of(rest), 9);
+        assert_eq!(tokens.next(), None);
+        assert_eq!(tokens.rest(), None);
+    }
+}
@@ -0,0 +1,196 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufWriter, Write};
+use std::path::Path;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
+use std::time::{Duration, Instant};
+use crate::Solution;
+
+// Days whose solution is a simulation worth watching. `part` picks which
+// part's simulation to show.
+pub trait Visualize: Solution {
+    fn visualize(input: &Self::Input, part: u8, visualizer: &mut Visualizer) -> io::Result<()>;
+}
+
+// Shows a simulation one frame at a time, either animated in the terminal
+// or written to a file. Simulations call `frame` after every step and stop
+// early once `stopped` is true.
+pub struct Visualizer {
+    output: Output,
+    // Minimum time between two shown frames
+    frame_time: Option<Duration>,
+    // Only every `every`th frame is shown
+    every: usize,
+    frames: usize,
+    shown: usize,
+    last_shown: Option<Instant>,
+    // The latest frame offered, if it was shown
+    latest: Option<String>,
+    stopped: bool,
+}
+
+enum Output {
+    Terminal {
+        // Lines typed while running, read on another thread so the
+        // animation doesn't wait for them
+        commands: Receiver<String>,
+        paused: bool,
+    },
+    Record(BufWriter<File>),
+}
+
+impl Visualizer {
+    // Animate in the terminal. Pressing Enter pauses; while paused, Enter
+    // shows the next frame, `c` continues and `q` stops.
+    pub fn terminal(fps: Option<f64>, every: usize, paused: bool) -> Self {
+        let (sender, commands) = mpsc::channel();
+        thread::spawn(move || {
+            for line in io::stdin().lock().lines() {
+                let Ok(line) = line else { break };
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+        Visualizer::new(Output::Terminal { commands, paused }, fps, every)
+    }
+
+    // Write every shown frame to `path` as fast as possible
+    pub fn record(path: &Path, every: usize) -> io::Result<Self> {
+        let file = BufWriter::new(File::create(path)?);
+        Ok(Visualizer::new(Output::Record(file), None, every))
+    }
+
+    fn new(output: Output, fps: Option<f64>, every: usize) -> Self {
+        Visualizer {
+            output,
+            frame_time: fps.filter(|&fps| fps > 0.0).map(|fps| Duration::from_secs_f64(1.0 / fps)),
+            every: every.max(1),
+            frames: 0,
+            shown: 0,
+            last_shown: None,
+            latest: None,
+            stopped: false,
+        }
+    }
+
+    pub fn stopped(&self) -> bool {
+        self.stopped
+    }
+
+    // Offer the next frame. `render` is only called for frames that are
+    // actually shown, so skipped frames cost nothing to draw.
+    pub fn frame(&mut self, render: impl FnOnce() -> String) -> io::Result<()> {
+        self.frames += 1;
+        if self.stopped || !(self.frames - 1).is_multiple_of(self.every) {
+            self.latest = None;
+            return Ok(());
+        }
+        self.show(render())
+    }
+
+    // Show the final state of the simulation, unless it's the frame that
+    // was just shown. A state that changed since then is shown as one more
+    // frame.
+    pub fn finish(&mut self, render: impl FnOnce() -> String) -> io::Result<()> {
+        if !self.stopped {
+            let frame = render();
+            match &self.latest {
+                Some(latest) if *latest == frame => (),
+                Some(_) => {
+                    self.frames += 1;
+                    self.show(frame)?;
+                }
+                None => self.show(frame)?,
+            }
+        }
+        match &mut self.output {
+            Output::Terminal { .. } => println!("{} frames, {} shown", self.frames, self.shown),
+            Output::Record(file) => file.flush()?,
+        }
+        Ok(())
+    }
+
+    fn show(&mut self, frame: String) -> io::Result<()> {
+        self.shown += 1;
+        match &mut self.output {
+            Output::Record(file) => {
+                writeln!(file, "--- frame {} ---", self.frames)?;
+                write!(file, "{}", frame)?;
+                if !frame.ends_with('\n') {
+                    writeln!(file)?;
+                }
+            }
+            Output::Terminal { commands, paused } => {
+                if let (Some(frame_time), Some(last)) = (self.frame_time, self.last_shown) {
+                    if let Some(wait) = frame_time.checked_sub(last.elapsed()) {
+                        thread::sleep(wait);
+                    }
+                }
+                let mut stdout = io::stdout().lock();
+                // Clear the screen and draw from the top left corner
+                write!(stdout, "\x1b[2J\x1b[1;1H{}", frame)?;
+                writeln!(stdout, "frame {}{}", self.frames, if *paused { " (paused: Enter, c, q)" } else { "" })?;
+                stdout.flush()?;
+                self.last_shown = Some(Instant::now());
+
+                if *paused {
+                    match commands.recv().as_deref().map(str::trim) {
+                        Ok("c") => *paused = false,
+                        Ok("q") | Err(_) => self.stopped = true,
+                        Ok(_) => (),
+                    }
+                } else {
+                    match commands.try_recv() {
+                        Ok(_) => *paused = true,
+                        Err(TryRecvError::Empty) => (),
+                        // Nothing more can be typed, so nothing can pause
+                        Err(TryRecvError::Disconnected) => (),
+                    }
+                }
+            }
+        }
+        self.latest = Some(frame);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    // Offers frames `step 1` to `step n`, then finishes with `last`
+    fn record(name: &str, n: usize, last: &str) -> (Vec<usize>, String) {
+        let path = std::env::temp_dir().join(format!("visual-{}-{}.txt", name, std::process::id()));
+        let mut visualizer = Visualizer::record(&path, 3).unwrap();
+        let mut rendered = Vec::new();
+        for i in 1..=n {
+            visualizer.frame(|| {
+                rendered.push(i);
+                format!("step {}", i)
+            }).unwrap();
+        }
+        visualizer.finish(|| last.to_string()).unwrap();
+        let text = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        (rendered, text)
+    }
+
+    #[test]
+    fn record_skips_frames() {
+        let (rendered, text) = record("shown", 7, "step 7");
+        assert_eq!(rendered, [1, 4, 7]);
+        assert_eq!(text.matches("--- frame").count(), 3);
+        assert!(text.starts_with("--- frame 1 ---\nstep 1\n--- frame 4 ---\n"));
+        assert!(text.ends_with("\n--- frame 7 ---\nstep 7\n"));
+
+        let (rendered, text) = record("changed", 7, "done\n");
+        assert_eq!(rendered, [1, 4, 7]);
+        assert!(text.ends_with("\n--- frame 7 ---\nstep 7\n--- frame 8 ---\ndone\n"));
+
+        let (rendered, text) = record("skipped", 8, "step 8");
+        assert_eq!(rendered, [1, 4, 7]);
+        assert!(text.ends_with("\n--- frame 7 ---\nstep 7\n--- frame 8 ---\nstep 8\n"));
+    }
+}
@@ -0,0 +1,49 @@
+use common::{parse, ParseError, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    // Total calories carried by each elf
+    type Input = Vec<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        let mut elves = Vec::new();
+        let mut sum = 0;
+        for line in parse::lines(text) {
+            if line.text.is_empty() {
+                elves.push(sum);
+                sum = 0;
+            } else {
+                sum += line.parse::<u32>(line.text, "calories")?;
+            }
+        }
+        // The last elf isn't followed by a blank line
+        elves.push(sum);
+        Ok(elves)
+    }
+
+    // Part 1: calories carried by the elf with the most
+    fn part1(elves: &Self::Input) -> u32 {
+        top_calories(elves, 1)
+    }
+
+    // Part 2: calories carried by the top three elves
+    fn part2(elves: &Self::Input) -> u32 {
+        top_calories(elves, 3)
+    }
+}
+
+fn top_calories(elves: &[u32], top_n: usize) -> u32 {
+    let mut max = vec![0; top_n]; // sorted greatest to least
+    for &elf in elves {
+        let mut sum = elf;
+        for max_item in max.iter_mut() {
+            if sum > *max_item {
+                std::mem::swap(max_item, &mut sum);
+            }
+        }
+    }
+    max.iter().sum::<u32>()
+}
@@ -0,0 +1,255 @@
+use std::collections::HashMap;
+use std::fmt;
+use common::{parse, ParseError};
+
+// Everything an instruction can change
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Registers {
+    pub x: i32,
+}
+
+impl Default for Registers {
+    fn default() -> Self {
+        Registers { x: 1 }
+    }
+}
+
+// How an instruction behaves: how many operands it takes, how many
+// cycles it lasts, and what it does to the registers once they're over
+#[derive(Debug, Clone, Copy)]
+pub struct Op {
+    pub operands: usize,
+    pub cycles: u32,
+    pub execute: fn(&mut Registers, &[i32]),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instruction {
+    pub name: String,
+    pub operands: Vec<i32>,
+}
+
+impl Instruction {
+    pub fn new(name: &str, operands: &[i32]) -> Self {
+        Instruction {
+            name: name.to_string(),
+            operands: operands.to_vec(),
+        }
+    }
+}
+
+// The way it's written in a program
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        for operand in &self.operands {
+            write!(f, " {}", operand)?;
+        }
+        Ok(())
+    }
+}
+
+// A program written back out, one instruction per line
+pub fn disassemble(program: &[Instruction]) -> String {
+    program.iter().map(|instruction| format!("{}\n", instruction)).collect()
+}
+
+// Assembled instructions next to the lines they came from
+pub fn listing(program: &[(usize, Instruction)]) -> String {
+    let width = program.last().map_or(1, |(line, _)| line.to_string().len());
+    program.iter()
+        .map(|(line, instruction)| format!("{:>width$}  {}\n", line, instruction, width = width))
+        .collect()
+}
+
+// The instructions a CPU understands, by name
+#[derive(Debug, Clone, Default)]
+pub struct InstructionSet {
+    ops: HashMap<String, Op>,
+}
+
+impl InstructionSet {
+    // `addx V` and `noop`, as the handheld has them
+    pub fn standard() -> Self {
+        let mut set = InstructionSet::default();
+        set.register("addx", Op { operands: 1, cycles: 2, execute: |registers, operands| registers.x += operands[0] });
+        set.register("noop", Op { operands: 0, cycles: 1, execute: |_, _| () });
+        set
+    }
+
+    // Add an instruction, replacing any with the same name
+    pub fn register(&mut self, name: &str, op: Op) {
+        assert!(op.cycles > 0, "`{}` has to take at least one cycle", name);
+        self.ops.insert(name.to_string(), op);
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Op> {
+        self.ops.get(name)
+    }
+
+    // Names in a stable order, for error messages
+    fn names(&self) -> String {
+        let mut names: Vec<String> = self.ops.keys().map(|name| format!("`{}`", name)).collect();
+        names.sort();
+        names.join(", ")
+    }
+
+    // One instruction per line: its name followed by its operands
+    pub fn parse_program(&self, text: &str) -> Result<Vec<Instruction>, ParseError> {
+        let program = self.assemble(text)?;
+        Ok(program.into_iter().map(|(_, instruction)| instruction).collect())
+    }
+
+    // Like `parse_program`, but keeping the line each instruction is on.
+    // Blank lines and comments starting with `;` are skipped, so programs
+    // written by hand can be annotated.
+    pub fn assemble(&self, text: &str) -> Result<Vec<(usize, Instruction)>, ParseError> {
+        let expected = format!("an instruction ({})", self.names());
+        let mut program = Vec::new();
+        for mut line in parse::lines(text) {
+            if let Some((code, _comment)) = line.text.split_once(';') {
+                line.text = code;
+            }
+            if line.text.trim().is_empty() {
+                continue;
+            }
+            let mut tokens = line.tokens();
+            let name = tokens.next_token(&expected)?;
+            let op = self.get(name).ok_or_else(|| line.error_at(name, &expected))?;
+            let mut operands = Vec::new();
+            for _ in 0..op.operands {
+                operands.push(tokens.parse::<i32>("a number")?);
+            }
+            tokens.end()?;
+            program.push((line.number, Instruction { name: name.to_string(), operands }));
+        }
+        Ok(program)
+    }
+}
+
+// What the CPU was doing during one cycle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    // Counting from 1
+    pub number: u32,
+    // Index of the instruction being executed
+    pub pc: usize,
+    // Registers during the cycle, before the instruction has finished
+    pub registers: Registers,
+    // Registers at the end of the cycle, which only differ from `registers`
+    // on the cycle that finishes an instruction
+    pub after: Registers,
+}
+
+// Runs a program one cycle at a time. Iterating yields every cycle until
+// the last instruction has finished.
+pub struct Cpu<'a> {
+    set: &'a InstructionSet,
+    program: &'a [Instruction],
+    registers: Registers,
+    cycle: u32,
+    pc: usize,
+    // Cycles left before the current instruction finishes
+    remaining: u32,
+}
+
+impl<'a> Cpu<'a> {
+    pub fn new(set: &'a InstructionSet, program: &'a [Instruction]) -> Self {
+        Cpu {
+            set,
+            program,
+            registers: Registers::default(),
+            cycle: 0,
+            pc: 0,
+            remaining: 0,
+        }
+    }
+
+    pub fn registers(&self) -> Registers {
+        self.registers
+    }
+
+    fn op(&self, instruction: &Instruction) -> &'a Op {
+        self.set.get(&instruction.name)
+            .unwrap_or_else(|| panic!("`{}` isn't in the instruction set", instruction.name))
+    }
+
+    // Run the whole program, calling `hook` for every cycle
+    pub fn run(&mut self, mut hook: impl FnMut(&Cycle)) {
+        for cycle in self.by_ref() {
+            hook(&cycle);
+        }
+    }
+}
+
+impl Iterator for Cpu<'_> {
+    type Item = Cycle;
+
+    fn next(&mut self) -> Option<Cycle> {
+        let instruction = self.program.get(self.pc)?;
+        let op = self.op(instruction);
+        if self.remaining == 0 {
+            self.remaining = op.cycles;
+        }
+
+        self.cycle += 1;
+        let number = self.cycle;
+        let pc = self.pc;
+        let registers = self.registers;
+
+        self.remaining -= 1;
+        if self.remaining == 0 {
+            (op.execute)(&mut self.registers, &instruction.operands);
+            self.pc += 1;
+        }
+        Some(Cycle { number, pc, registers, after: self.registers })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_program() {
+        let set = InstructionSet::standard();
+        let program = set.parse_program("noop\naddx 3\naddx -5\n").unwrap();
+        let xs: Vec<i32> = Cpu::new(&set, &program).map(|cycle| cycle.registers.x).collect();
+        assert_eq!(xs, [1, 1, 1, 4, 4]);
+
+        let mut cpu = Cpu::new(&set, &program);
+        cpu.run(|_| ());
+        assert_eq!(cpu.registers().x, -1);
+    }
+
+    #[test]
+    fn registered_instruction() {
+        let mut set = InstructionSet::standard();
+        set.register("mulx", Op { operands: 1, cycles: 3, execute: |registers, operands| registers.x *= operands[0] });
+        let program = set.parse_program("addx 2\nmulx 4\n").unwrap();
+        let xs: Vec<i32> = Cpu::new(&set, &program).map(|cycle| cycle.registers.x).collect();
+        assert_eq!(xs, [1, 1, 3, 3, 3]);
+
+        let err = InstructionSet::standard().parse_program("mulx 4").unwrap_err();
+        assert_eq!(err.to_string(), "<input>:1:1: expected an instruction (`addx`, `noop`), found `mulx`");
+    }
+
+    #[test]
+    fn assemble_and_disassemble() {
+        let set = InstructionSet::standard();
+        let text = "; count down\nnoop\n\naddx   3 ; up\naddx -5\n";
+        let program = set.assemble(text).unwrap();
+        assert_eq!(program, [
+            (2, Instruction::new("noop", &[])),
+            (4, Instruction::new("addx", &[3])),
+            (5, Instruction::new("addx", &[-5])),
+        ]);
+        assert_eq!(listing(&program), "2  noop\n4  addx 3\n5  addx -5\n");
+
+        let instructions = set.parse_program(text).unwrap();
+        assert_eq!(disassemble(&instructions), "noop\naddx 3\naddx -5\n");
+
+        let err = set.assemble("noop\naddx ;3\n").unwrap_err();
+        assert_eq!(err.to_string(), "<input>:2:6: expected a number, found end of line");
+    }
+}
@@ -0,0 +1,121 @@
+use grid::Grid;
+use crate::cpu::Cycle;
+
+pub const WIDTH: usize = 40;
+pub const HEIGHT: usize = 6;
+
+// Letters are 4 pixels wide with a blank column after each
+const GLYPH_WIDTH: usize = 4;
+const GLYPH_SPACING: usize = GLYPH_WIDTH + 1;
+
+// The capital letters of the font the handheld draws with. Not every
+// letter has turned up in a puzzle, so the ones that haven't are missing.
+const FONT: [(char, [&str; HEIGHT]); 17] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+// The CRT's pixels, lit as the CPU runs. Draws one pixel per cycle, left
+// to right and top to bottom, and a pixel is lit when the three pixel wide
+// sprite centred on X covers it.
+pub struct Crt {
+    pixels: Grid<bool>,
+}
+
+impl Default for Crt {
+    fn default() -> Self {
+        Crt::new()
+    }
+}
+
+impl Crt {
+    pub fn new() -> Self {
+        Crt { pixels: Grid::new(WIDTH, HEIGHT, false) }
+    }
+
+    pub fn pixels(&self) -> &Grid<bool> {
+        &self.pixels
+    }
+
+    // Position of the pixel drawn during `cycle`, or None once the beam has
+    // gone past the bottom of the screen
+    pub fn beam(cycle: &Cycle) -> Option<(usize, usize)> {
+        let index = cycle.number as usize - 1;
+        let pos = (index % WIDTH, index / WIDTH);
+        (pos.1 < HEIGHT).then_some(pos)
+    }
+
+    // Whether the pixel drawn during `cycle` is lit
+    pub fn lit(cycle: &Cycle) -> bool {
+        let column = ((cycle.number as usize - 1) % WIDTH) as i32;
+        (column - cycle.registers.x).abs() <= 1
+    }
+
+    pub fn draw(&mut self, cycle: &Cycle) {
+        if let Some(pos) = Crt::beam(cycle) {
+            self.pixels[pos] = Crt::lit(cycle);
+        }
+    }
+
+    pub fn render(&self) -> String {
+        self.pixels.render(|&lit| if lit { '#' } else { '.' })
+    }
+
+    // The letters shown on the screen, or None if any of them isn't in the
+    // font
+    pub fn read(&self) -> Option<String> {
+        (0..WIDTH / GLYPH_SPACING).map(|i| self.letter(i * GLYPH_SPACING)).collect()
+    }
+
+    fn letter(&self, left: usize) -> Option<char> {
+        let matches = |glyph: &[&str; HEIGHT]| {
+            glyph.iter().enumerate().all(|(y, row)| {
+                row.chars().enumerate().all(|(x, c)| self.pixels[(left + x, y)] == (c == '#'))
+            }) && (0..HEIGHT).all(|y| left + GLYPH_WIDTH >= WIDTH || !self.pixels[(left + GLYPH_WIDTH, y)])
+        };
+        FONT.iter().find(|(_, glyph)| matches(glyph)).map(|&(letter, _)| letter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn screen(text: &str) -> Crt {
+        Crt { pixels: Grid::parse(text, "`#` or `.`", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        }).unwrap() }
+    }
+
+    #[test]
+    fn read_letters() {
+        let crt = screen("\
+####..##..#....#..#.###..#....####...##.
+#....#..#.#....#..#.#..#.#....#.......#.
+###..#....#....####.###..#....###.....#.
+#....#.##.#....#..#.#..#.#....#.......#.
+#....#..#.#....#..#.#..#.#....#....#..#.
+####..###.####.#..#.###..####.#.....##..
+");
+        assert_eq!(crt.read().as_deref(), Some("EGLHBLFJ"));
+
+        assert_eq!(Crt::new().read(), None);
+    }
+}
@@ -0,0 +1,44 @@
+use common::{ParseError, Solution};
+
+mod cpu;
+mod crt;
+mod signal;
+mod trace;
+pub use cpu::{disassemble, listing, Cpu, Cycle, Instruction, InstructionSet, Op, Registers};
+pub use crt::Crt;
+pub use signal::{Schedule, Signal};
+pub use trace::{to_csv, trace, Step};
+
+// Cycles during which the signal strength is sampled
+pub const SAMPLES: [u32; 6] = [20, 60, 100, 140, 180, 220];
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    // The program run by the handheld's CPU
+    type Input = Vec<Instruction>;
+    type Answer1 = i32;
+    type Answer2 = String;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        InstructionSet::standard().parse_program(text)
+    }
+
+    // Part 1: sum of the signal strengths during the sampled cycles that
+    // the program lasts for
+    fn part1(program: &Self::Input) -> i32 {
+        let signal = Signal::new(&InstructionSet::standard(), program);
+        signal.sample(&Schedule::Cycles(SAMPLES.to_vec())).into_iter()
+            .filter_map(|(_, strength)| strength)
+            .sum()
+    }
+
+    // Part 2: letters drawn on the CRT. Images that aren't letters, like
+    // the example's, are given as drawn.
+    fn part2(program: &Self::Input) -> String {
+        let set = InstructionSet::standard();
+        let mut crt = Crt::new();
+        Cpu::new(&set, program).run(|cycle| crt.draw(cycle));
+        crt.read().unwrap_or_else(|| crt.render())
+    }
+}
@@ -0,0 +1,65 @@
+use crate::cpu::{Cpu, Instruction, InstructionSet};
+
+// Cycles at which to sample the signal
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Schedule {
+    Cycles(Vec<u32>),
+    // `start`, `start + step`, ... for as long as the program runs.
+    // `step` has to be at least 1.
+    Every { start: u32, step: u32 },
+}
+
+// X during every cycle of one run of a program
+pub struct Signal {
+    xs: Vec<i32>,
+}
+
+impl Signal {
+    pub fn new(set: &InstructionSet, program: &[Instruction]) -> Self {
+        Signal { xs: Cpu::new(set, program).map(|cycle| cycle.registers.x).collect() }
+    }
+
+    // Number of cycles the program ran for
+    pub fn cycles(&self) -> u32 {
+        self.xs.len() as u32
+    }
+
+    // X during `cycle`, or None if the program had already finished
+    pub fn x(&self, cycle: u32) -> Option<i32> {
+        self.xs.get(cycle.checked_sub(1)? as usize).copied()
+    }
+
+    // The cycle number times X during that cycle
+    pub fn strength(&self, cycle: u32) -> Option<i32> {
+        Some(cycle as i32 * self.x(cycle)?)
+    }
+
+    // The strength at each scheduled cycle. A list of cycles gives one
+    // sample for each of them, a repeating schedule stops with the program.
+    pub fn sample(&self, schedule: &Schedule) -> Vec<(u32, Option<i32>)> {
+        match schedule {
+            Schedule::Cycles(cycles) => cycles.iter().map(|&cycle| (cycle, self.strength(cycle))).collect(),
+            &Schedule::Every { start, step } => {
+                assert!(step > 0, "a schedule's step has to be at least 1");
+                (start..=self.cycles()).step_by(step as usize)
+                    .map(|cycle| (cycle, self.strength(cycle)))
+                    .collect()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schedules() {
+        let set = InstructionSet::standard();
+        let program = set.parse_program("noop\naddx 3\naddx -5\n").unwrap();
+        let signal = Signal::new(&set, &program);
+        assert_eq!(signal.sample(&Schedule::Cycles(vec![0, 4, 6])), [(0, None), (4, Some(16)), (6, None)]);
+        assert_eq!(signal.sample(&Schedule::Every { start: 1, step: 2 }), [(1, Some(1)), (3, Some(3)), (5, Some(20))]);
+        assert_eq!(signal.sample(&Schedule::Every { start: 7, step: 2 }), []);
+    }
+}
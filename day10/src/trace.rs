@@ -0,0 +1,71 @@
+use crate::cpu::{Cpu, Instruction, InstructionSet};
+use crate::crt::Crt;
+
+// One cycle of a program's execution
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    pub cycle: u32,
+    // Source line of the instruction being executed
+    pub line: usize,
+    pub instruction: String,
+    pub x_before: i32,
+    pub x_after: i32,
+    // Position of the pixel the CRT drew and whether it was lit, if the
+    // beam was still on the screen
+    pub pixel: Option<((usize, usize), bool)>,
+}
+
+// Every cycle of running an assembled program
+pub fn trace(set: &InstructionSet, program: &[(usize, Instruction)]) -> Vec<Step> {
+    let instructions: Vec<Instruction> = program.iter().map(|(_, instruction)| instruction.clone()).collect();
+    Cpu::new(set, &instructions).map(|cycle| {
+        let (line, instruction) = &program[cycle.pc];
+        Step {
+            cycle: cycle.number,
+            line: *line,
+            instruction: instruction.to_string(),
+            x_before: cycle.registers.x,
+            x_after: cycle.after.x,
+            pixel: Crt::beam(&cycle).map(|pos| (pos, Crt::lit(&cycle))),
+        }
+    }).collect()
+}
+
+// The trace with a header row. Pixels off the screen leave their columns
+// empty.
+pub fn to_csv(steps: &[Step]) -> String {
+    let mut csv = String::from("cycle,line,instruction,x_before,x_after,pixel_x,pixel_y,lit\n");
+    for step in steps {
+        let pixel = match step.pixel {
+            Some(((x, y), lit)) => format!("{},{},{}", x, y, lit),
+            None => ",,".to_string(),
+        };
+        csv.push_str(&format!(
+            "{},{},\"{}\",{},{},{}\n",
+            step.cycle, step.line, step.instruction.replace('"', "\"\""), step.x_before, step.x_after, pixel
+        ));
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trace_to_csv() {
+        let set = InstructionSet::standard();
+        let program = set.assemble("noop\n; up\naddx 3\n").unwrap();
+        let steps = trace(&set, &program);
+        assert_eq!(steps.len(), 3);
+        assert_eq!(steps[2], Step {
+            cycle: 3,
+            line: 3,
+            instruction: "addx 3".to_string(),
+            x_before: 1,
+            x_after: 4,
+            pixel: Some(((2, 0), true)),
+        });
+        assert_eq!(to_csv(&steps[..1]), "cycle,line,instruction,x_before,x_after,pixel_x,pixel_y,lit\n1,1,\"noop\",1,1,0,0,true\n");
+    }
+}
@@ -0,0 +1,188 @@
+use common::parse::Line;
+use common::ParseError;
+use crate::worry::Worry;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+// How a monkey's inspection changes an item's worry level
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    Old,
+    Literal(u128),
+    Binary(Box<Expr>, Operator, Box<Expr>),
+}
+
+impl Expr {
+    // The new worry level for an item whose worry level is `old`, or None
+    // if it can't be kept in a `W`
+    pub fn eval<W: Worry>(&self, old: &W) -> Option<W> {
+        match self {
+            Expr::Old => Some(old.clone()),
+            Expr::Literal(n) => Some(W::from(*n)),
+            Expr::Binary(left, operator, right) => {
+                let (left, right) = (left.eval(old)?, right.eval(old)?);
+                match operator {
+                    Operator::Add => left.checked_add(&right),
+                    Operator::Sub => left.checked_sub(&right),
+                    Operator::Mul => left.checked_mul(&right),
+                    Operator::Div => left.checked_div(&right),
+                }
+            }
+        }
+    }
+
+    // Like `eval`, but working modulo `modulus`: `old` and every result on
+    // the way are kept below it, and subtraction wraps around it instead of
+    // going negative. Division doesn't carry over to remainders, so an
+    // expression with `/` gives None.
+    pub fn eval_modulo<W: Worry>(&self, old: &W, modulus: &W) -> Option<W> {
+        match self {
+            Expr::Old => old.checked_rem(modulus),
+            Expr::Literal(n) => W::from(*n).checked_rem(modulus),
+            Expr::Binary(left, operator, right) => {
+                let (left, right) = (left.eval_modulo(old, modulus)?, right.eval_modulo(old, modulus)?);
+                let result = match operator {
+                    Operator::Add => left.checked_add(&right),
+                    Operator::Sub => left.checked_add(modulus)?.checked_sub(&right),
+                    Operator::Mul => left.checked_mul(&right),
+                    Operator::Div => None,
+                }?;
+                result.checked_rem(modulus)
+            }
+        }
+    }
+
+    // Whether the expression divides anywhere
+    pub fn divides(&self) -> bool {
+        match self {
+            Expr::Old | Expr::Literal(_) => false,
+            Expr::Binary(left, operator, right) => {
+                *operator == Operator::Div || left.divides() || right.divides()
+            }
+        }
+    }
+
+    // Parse `text`, a slice of `line`, as a whole expression. `*` and `/`
+    // bind tighter than `+` and `-`, and parentheses group.
+    pub fn parse(line: Line, text: &str) -> Result<Self, ParseError> {
+        let mut parser = Parser { line, rest: text };
+        let expr = parser.sum()?;
+        match parser.peek() {
+            Some(token) => Err(line.error_at(token, "an operator or end of line")),
+            None => Ok(expr),
+        }
+    }
+}
+
+struct Parser<'a> {
+    line: Line<'a>,
+    rest: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    // The next token: a word, a number or a single symbol
+    fn peek(&mut self) -> Option<&'a str> {
+        self.rest = self.rest.trim_start();
+        let first = self.rest.chars().next()?;
+        let len = if first.is_ascii_alphanumeric() {
+            self.rest.find(|c: char| !c.is_ascii_alphanumeric()).unwrap_or(self.rest.len())
+        } else {
+            first.len_utf8()
+        };
+        Some(&self.rest[..len])
+    }
+
+    fn next(&mut self, expected: &str) -> Result<&'a str, ParseError> {
+        let token = self.peek().ok_or_else(|| self.line.error_at_end(expected))?;
+        self.rest = &self.rest[token.len()..];
+        Ok(token)
+    }
+
+    fn operator(&mut self, operators: &[(&str, Operator)]) -> Option<Operator> {
+        let token = self.peek()?;
+        let &(_, operator) = operators.iter().find(|(symbol, _)| *symbol == token)?;
+        self.rest = &self.rest[token.len()..];
+        Some(operator)
+    }
+
+    fn sum(&mut self) -> Result<Expr, ParseError> {
+        let mut expr = self.product()?;
+        while let Some(operator) = self.operator(&[("+", Operator::Add), ("-", Operator::Sub)]) {
+            expr = Expr::Binary(Box::new(expr), operator, Box::new(self.product()?));
+        }
+        Ok(expr)
+    }
+
+    fn product(&mut self) -> Result<Expr, ParseError> {
+        let mut expr = self.operand()?;
+        while let Some(operator) = self.operator(&[("*", Operator::Mul), ("/", Operator::Div)]) {
+            let token = self.peek();
+            let right = self.operand()?;
+            if let (Operator::Div, Expr::Literal(0), Some(token)) = (operator, &right, token) {
+                return Err(self.line.error_at(token, "a divisor other than 0"));
+            }
+            expr = Expr::Binary(Box::new(expr), operator, Box::new(right));
+        }
+        Ok(expr)
+    }
+
+    fn operand(&mut self) -> Result<Expr, ParseError> {
+        const EXPECTED: &str = "`old`, a number or `(`";
+        match self.next(EXPECTED)? {
+            "old" => Ok(Expr::Old),
+            "(" => {
+                let expr = self.sum()?;
+                match self.next("`)`")? {
+                    ")" => Ok(expr),
+                    token => Err(self.line.error_at(token, "`)`")),
+                }
+            }
+            token => self.line.parse::<u128>(token, EXPECTED).map(Expr::Literal),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str) -> Result<Expr, ParseError> {
+        Expr::parse(Line { number: 1, text }, text)
+    }
+
+    #[test]
+    fn precedence_and_parentheses() {
+        assert_eq!(parse("old * 19").unwrap().eval(&2u128), Some(38));
+        assert_eq!(parse("old + 2 * old").unwrap().eval(&3u128), Some(9));
+        assert_eq!(parse("(old + 2) * old").unwrap().eval(&3u128), Some(15));
+        assert_eq!(parse("old - 10 - 2").unwrap().eval(&20u128), Some(8));
+        assert_eq!(parse("((old))/4").unwrap().eval(&9u128), Some(2));
+        assert_eq!(parse("old - 10").unwrap().eval(&9u128), None);
+        assert_eq!(parse("old * old").unwrap().eval(&u128::MAX), None);
+    }
+
+    #[test]
+    fn modulo() {
+        assert_eq!(parse("old * 19").unwrap().eval_modulo(&2u128, &7), Some(3));
+        assert_eq!(parse("old - 10").unwrap().eval_modulo(&9u128, &7), Some(6));
+        assert_eq!(parse("old - 10").unwrap().eval_modulo(&3u128, &7), Some(0));
+        assert_eq!(parse("(old + 100) * old - old * old").unwrap().eval_modulo(&30u128, &7), Some(4));
+        assert_eq!(parse("old / 2").unwrap().eval_modulo(&4u128, &7), None);
+        assert!(parse("old * (19 + old / 2)").unwrap().divides());
+        assert!(!parse("old * (19 + old - 2)").unwrap().divides());
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(parse("old ^ 2").unwrap_err().to_string(), "<input>:1:5: expected an operator or end of line, found `^`");
+        assert_eq!(parse("(old + 1").unwrap_err().to_string(), "<input>:1:9: expected `)`, found end of line");
+        assert_eq!(parse("old * -3").unwrap_err().to_string(), "<input>:1:7: expected `old`, a number or `(`, found `-`");
+        assert_eq!(parse("old / 0").unwrap_err().to_string(), "<input>:1:7: expected a divisor other than 0, found `0`");
+    }
+}
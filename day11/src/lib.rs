@@ -0,0 +1,424 @@
+#[cfg(feature = "bigint")]
+use num_bigint::BigUint;
+use common::parse::{self, Line};
+use common::{ParseError, Solution};
+
+mod expr;
+mod trajectory;
+mod worry;
+pub use expr::{Expr, Operator};
+pub use trajectory::{inspections_after, trajectories, Trajectory};
+pub use worry::{lcm, Overflow, Worry};
+
+// How worry levels are kept from growing after each inspection
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relief {
+    // Divided by this, rounding down
+    Divide(u128),
+    // Taken modulo the least common multiple of the monkeys' divisors,
+    // which leaves where every monkey throws them unchanged. Subtraction
+    // wraps around the modulus, and no operation can divide.
+    Modulo,
+    // Not at all. Worry levels are kept as big integers with the `bigint`
+    // feature, and overflow quickly without it, so this is only feasible
+    // for a few rounds.
+    None,
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    // Worry levels of the items the monkey starts with
+    items: Vec<u128>,
+    operation: Expr,
+    test: u128,
+    next: (usize, usize),
+}
+
+impl Monkey {
+    // Which monkey an item with this worry level is thrown to
+    fn target<W: Worry>(&self, worry: &W) -> usize {
+        // Divisors are never 0
+        if worry.checked_rem(&W::from(self.test)) == Some(W::from(0)) {
+            self.next.0
+        } else {
+            self.next.1
+        }
+    }
+}
+
+// Smallest number that every monkey's divisor divides, so worry levels can
+// be taken modulo it without changing where any item is thrown. Fails if a
+// monkey's operation divides, since remainders can't be divided.
+pub fn modulus(monkeys: &[Monkey]) -> Result<u128, Overflow> {
+    if let Some(monkey) = monkeys.iter().position(|m| m.operation.divides()) {
+        return Err(Overflow::Division { monkey });
+    }
+    lcm(monkeys.iter().map(|m| m.test)).ok_or(Overflow::Modulus)
+}
+
+// An item being thrown around. Items are numbered in the order the
+// monkeys hold them at the start.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Item<W> {
+    pub id: usize,
+    pub worry: W,
+}
+
+// The monkeys playing keep away. Each monkey's items are held in a queue
+// and moved between queues in place.
+pub struct Troop<'a, W> {
+    monkeys: &'a [Monkey],
+    relief: Relief,
+    modulus: u128,
+    // Rounds played so far
+    rounds: usize,
+    items: Vec<Vec<Item<W>>>,
+    inspected: Vec<u64>,
+    // Every monkey each item has been held by and its worry level when
+    // thrown there, if tracked
+    paths: Option<Vec<Vec<(usize, W)>>>,
+}
+
+impl<'a, W: Worry> Troop<'a, W> {
+    // Fails if worry is divided by 0, or kept modulo a number too large to
+    // work out or that an operation can't be worked out modulo
+    pub fn new(monkeys: &'a [Monkey], relief: Relief) -> Result<Self, Overflow> {
+        let mut id = 0;
+        let items = monkeys.iter().map(|m| {
+            m.items.iter().map(|&worry| {
+                id += 1;
+                Item { id: id - 1, worry: W::from(worry) }
+            }).collect()
+        }).collect();
+        let modulus = match relief {
+            Relief::Divide(0) => return Err(Overflow::DivideByZero),
+            Relief::Modulo => modulus(monkeys)?,
+            _ => 0,
+        };
+        Ok(Troop {
+            monkeys,
+            relief,
+            modulus,
+            rounds: 0,
+            items,
+            inspected: vec![0; monkeys.len()],
+            paths: None,
+        })
+    }
+
+    // Start recording where every item goes
+    pub fn track(&mut self) {
+        let mut paths = Vec::new();
+        for (idx, items) in self.items.iter().enumerate() {
+            for item in items {
+                if paths.len() <= item.id {
+                    paths.resize(item.id + 1, Vec::new());
+                }
+                paths[item.id] = vec![(idx, item.worry.clone())];
+            }
+        }
+        self.paths = Some(paths);
+    }
+
+    // The path of each item by id, starting with where it was when
+    // tracking started
+    pub fn paths(&self) -> Option<&[Vec<(usize, W)>]> {
+        self.paths.as_deref()
+    }
+
+    // Every monkey takes a turn, in order. Stops at the first worry level
+    // that can't be kept in a `W`, partway through the round.
+    pub fn round(&mut self) -> Result<(), Overflow> {
+        self.rounds += 1;
+        for idx in 0..self.monkeys.len() {
+            self.turn(idx)?;
+        }
+        Ok(())
+    }
+
+    // The monkey inspects each of its items and throws it on. Items thrown
+    // to monkeys later in the round get inspected again this round.
+    fn turn(&mut self, idx: usize) -> Result<(), Overflow> {
+        let monkey = &self.monkeys[idx];
+        let items = std::mem::take(&mut self.items[idx]);
+        self.inspected[idx] += items.len() as u64;
+        for item in items {
+            let worry = self.inspect(&monkey.operation, &item.worry)
+                .ok_or(Overflow::Worry { round: self.rounds, monkey: idx, item: item.id })?;
+            let target = monkey.target(&worry);
+            if let Some(paths) = &mut self.paths {
+                paths[item.id].push((target, worry.clone()));
+            }
+            self.items[target].push(Item { id: item.id, worry });
+        }
+        Ok(())
+    }
+
+    // The worry level after `operation` and relief
+    fn inspect(&self, operation: &Expr, worry: &W) -> Option<W> {
+        match self.relief {
+            Relief::Divide(n) => operation.eval(worry)?.checked_div(&W::from(n)),
+            Relief::Modulo => operation.eval_modulo(worry, &W::from(self.modulus)),
+            Relief::None => operation.eval(worry),
+        }
+    }
+
+    // Number of items each monkey has inspected so far
+    pub fn inspected(&self) -> &[u64] {
+        &self.inspected
+    }
+
+    // Product of the two highest numbers of items inspected
+    pub fn monkey_business(&self) -> u128 {
+        let mut inspected = self.inspected.clone();
+        inspected.sort();
+        inspected.iter().rev().take(2).map(|&n| n as u128).product()
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    // The monkeys in the order they take their turns
+    type Input = Vec<Monkey>;
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        parse_input(text)
+    }
+
+    // Part 1: 20 rounds, worry is divided by 3 after each inspection
+    fn part1(monkeys: &Self::Input) -> u128 {
+        monkey_business(monkeys, Relief::Divide(3), 20).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    // Part 2: 10000 rounds, worry is only kept in check by the divisors
+    fn part2(monkeys: &Self::Input) -> u128 {
+        monkey_business(monkeys, Relief::Modulo, 10000).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+// Product of the two highest numbers of items inspected after `rounds`
+// rounds. Worry levels are big integers if nothing keeps them in check and
+// the `bigint` feature is on.
+pub fn monkey_business(monkeys: &[Monkey], relief: Relief, rounds: usize) -> Result<u128, Overflow> {
+    fn simulate<W: Worry>(monkeys: &[Monkey], relief: Relief, rounds: usize) -> Result<u128, Overflow> {
+        let mut troop = Troop::<W>::new(monkeys, relief)?;
+        for _ in 0..rounds {
+            troop.round()?;
+        }
+        Ok(troop.monkey_business())
+    }
+
+    match relief {
+        #[cfg(feature = "bigint")]
+        Relief::None => simulate::<BigUint>(monkeys, relief, rounds),
+        _ => simulate::<u128>(monkeys, relief, rounds),
+    }
+}
+
+// Monkeys are described in blocks of six lines, separated by blank lines:
+//
+// Monkey 0:
+//   Starting items: 79, 98
+//   Operation: new = old * 19
+//   Test: divisible by 23
+//     If true: throw to monkey 2
+//     If false: throw to monkey 3
+fn parse_input(input_str: &str) -> Result<Vec<Monkey>, ParseError> {
+    let mut lines = parse::lines(input_str).peekable();
+    let mut monkeys = Vec::new();
+    // Where each monkey throws to, checked once every monkey is known
+    let mut targets = Vec::new();
+    loop {
+        let idx = monkeys.len();
+        monkeys.push(parse_monkey(&mut lines, input_str, idx, &mut targets)?);
+        match lines.next() {
+            None => break,
+            Some(line) if line.text.trim().is_empty() => {
+                if lines.peek().is_none() {
+                    break;
+                }
+            }
+            Some(line) => return Err(line.error_at(line.text.trim(), "a blank line between monkeys")),
+        }
+    }
+
+    for Target { line, token, monkey, branch, target } in targets {
+        if target >= monkeys.len() {
+            let expected = format!("a monkey number below {} for monkey {} `If {}`", monkeys.len(), monkey, branch);
+            return Err(line.error_at(token, &expected));
+        }
+    }
+    Ok(monkeys)
+}
+
+// A monkey that `monkey` throws to, with where it was written
+struct Target<'a> {
+    line: Line<'a>,
+    token: &'a str,
+    monkey: usize,
+    branch: &'static str,
+    target: usize,
+}
+
+fn parse_monkey<'a>(
+    lines: &mut impl Iterator<Item = Line<'a>>,
+    text: &str,
+    idx: usize,
+    targets: &mut Vec<Target<'a>>,
+) -> Result<Monkey, ParseError> {
+    // The next line, which has to start with `keyword`
+    let mut field = |keyword: &str| {
+        let expected = format!("`{}` for monkey {}", keyword, idx);
+        let line = lines.next().ok_or_else(|| parse::unexpected_end(text, &expected))?;
+        let mut tokens = line.tokens();
+        match tokens.next_token(&expected)? {
+            token if token == keyword => Ok(tokens),
+            token => Err(line.error_at(token, &expected)),
+        }
+    };
+
+    let mut tokens = field("Monkey")?;
+    tokens.expect(&format!("{}:", idx))?;
+    tokens.end()?;
+
+    let mut tokens = field("Starting")?;
+    tokens.expect("items:")?;
+    let line = tokens.line();
+    let expected = format!("a worry level for monkey {}", idx);
+    let mut items = Vec::new();
+    let mut tokens = tokens.peekable();
+    while let Some(token) = tokens.next() {
+        // Every item but the last is followed by a comma
+        let item = match (token.strip_suffix(','), tokens.peek()) {
+            (Some(item), Some(_)) => item,
+            (None, None) => token,
+            (Some(_), None) => return Err(line.error_at_end(&expected)),
+            (None, Some(next)) => return Err(line.error_at(next, "`,` between worry levels")),
+        };
+        items.push(line.parse::<u128>(item, &expected)?);
+    }
+
+    let mut tokens = field("Operation:")?;
+    let operation = parse_operation(&mut tokens)?;
+
+    let mut tokens = field("Test:")?;
+    tokens.expect("divisible")?;
+    tokens.expect("by")?;
+    let expected = format!("a divisor other than 0 for monkey {}", idx);
+    let token = tokens.next_token(&expected)?;
+    let test = match tokens.line().parse::<u128>(token, &expected)? {
+        0 => return Err(tokens.line().error_at(token, &expected)),
+        test => test,
+    };
+    tokens.end()?;
+
+    let mut next = [0; 2];
+    for (branch, target) in ["true:", "false:"].into_iter().zip(&mut next) {
+        let mut tokens = field("If")?;
+        tokens.expect(branch)?;
+        tokens.expect("throw")?;
+        tokens.expect("to")?;
+        tokens.expect("monkey")?;
+        let line = tokens.line();
+        let token = tokens.next_token("a monkey number")?;
+        *target = line.parse::<usize>(token, "a monkey number")?;
+        targets.push(Target { line, token, monkey: idx, branch, target: *target });
+        tokens.end()?;
+    }
+
+    Ok(Monkey { items, operation, test, next: (next[0], next[1]) })
+}
+
+// Operations look like `new = old * 19`, where the right hand side can be
+// any expression of `old` and numbers
+fn parse_operation(tokens: &mut parse::Tokens) -> Result<Expr, ParseError> {
+    let line = tokens.line();
+    tokens.expect("new")?;
+    tokens.expect("=")?;
+    let text = tokens.rest().ok_or_else(|| line.error_at_end("an expression"))?;
+    Expr::parse(line, text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn relief_policies() {
+        let monkeys = Day11::parse(include_str!("../test.txt")).unwrap();
+        assert_eq!(monkey_business(&monkeys, Relief::Divide(3), 20), Ok(10605));
+        assert_eq!(monkey_business(&monkeys, Relief::Divide(0), 20), Err(Overflow::DivideByZero));
+        // Keeping worry modulo the divisors throws items the same way as
+        // not keeping it in check at all
+        #[cfg(feature = "bigint")]
+        assert_eq!(monkey_business(&monkeys, Relief::Modulo, 12), monkey_business(&monkeys, Relief::None, 12));
+    }
+
+    #[test]
+    fn modulo_operations() {
+        let example = include_str!("../test.txt");
+        // Monkey 3 throws item 9 to monkey 1 with a worry level of 10 once
+        // it's taken modulo the divisors, which wraps around when monkey 1
+        // subtracts 50. Real worry levels never go below 54.
+        let text = example.replacen("items: 74\n", "items: 96584\n", 1).replacen("old + 6", "old - 50 + 56", 1);
+        let monkeys = Day11::parse(&text).unwrap();
+        #[cfg(feature = "bigint")]
+        assert_eq!(monkey_business(&monkeys, Relief::Modulo, 12), monkey_business(&monkeys, Relief::None, 12));
+        assert!(monkey_business(&monkeys, Relief::Modulo, 10000).is_ok());
+        let mut troop = Troop::<u128>::new(&monkeys, Relief::Modulo).unwrap();
+        for _ in 0..1000 {
+            troop.round().unwrap();
+        }
+        assert_eq!(inspections_after(&monkeys, 1000).unwrap(), troop.inspected());
+
+        let monkeys = Day11::parse(&example.replacen("old * 19", "old * 19 / 2", 1)).unwrap();
+        assert_eq!(monkey_business(&monkeys, Relief::Modulo, 200), Err(Overflow::Division { monkey: 0 }));
+        assert_eq!(inspections_after(&monkeys, 200), Err(Overflow::Division { monkey: 0 }));
+        assert!(monkey_business(&monkeys, Relief::Divide(3), 20).is_ok());
+    }
+
+    #[test]
+    fn overflow() {
+        let monkeys = Day11::parse(include_str!("../test.txt")).unwrap();
+        let mut troop = Troop::<u128>::new(&monkeys, Relief::None).unwrap();
+        let overflow = (0..40).map(|_| troop.round()).find_map(Result::err);
+        assert_eq!(overflow, Some(Overflow::Worry { round: 31, monkey: 0, item: 9 }));
+    }
+
+    #[test]
+    fn extrapolate_from_cycles() {
+        let monkeys = Day11::parse(include_str!("../test.txt")).unwrap();
+        let mut troop = Troop::<u128>::new(&monkeys, Relief::Modulo).unwrap();
+        troop.track();
+        for rounds in 1..=1000 {
+            troop.round().unwrap();
+            if [1, 20, 1000].contains(&rounds) {
+                assert_eq!(inspections_after(&monkeys, rounds).unwrap(), troop.inspected());
+            }
+        }
+        assert_eq!(inspections_after(&monkeys, 1000).unwrap(), [5204, 4792, 199, 5192]);
+
+        // Item 0 starts with monkey 0, which throws it to monkey 3
+        let paths = troop.paths().unwrap();
+        assert_eq!(paths.len(), 10);
+        assert_eq!(paths[0][..2], [(0, 79), (3, 1501)]);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let example = include_str!("../test.txt");
+        let error = |text: &str| Day11::parse(text).unwrap_err().to_string();
+
+        assert_eq!(error(&example.replacen("Monkey 1:", "Monkey 2:", 1)), "<input>:8:8: expected `1:`, found `2:`");
+        assert_eq!(error(&example.replacen("  Test: divisible by 19\n", "", 1)), "<input>:11:5: expected `Test:` for monkey 1, found `If`");
+        assert_eq!(error(&example.replacen("divisible by 19", "divisible by 0", 1)), "<input>:11:22: expected a divisor other than 0 for monkey 1, found `0`");
+        assert_eq!(error(&example.replacen("54, 65", "-54, 65", 1)), "<input>:9:19: expected a worry level for monkey 1, found `-54`");
+        assert_eq!(error(&example.replacen("54, 65", "54 65", 1)), "<input>:9:22: expected `,` between worry levels, found `65,`");
+        assert_eq!(error(&example.replacen("throw to monkey 0", "throw to monkey 4", 1)), "<input>:13:31: expected a monkey number below 4 for monkey 1 `If false:`, found `4`");
+        assert_eq!(error(&example.replacen("throw to monkey 2", "throw to monkey 7", 1)), "<input>:5:30: expected a monkey number below 4 for monkey 0 `If true:`, found `7`");
+        assert_eq!(error(example.trim_end().rsplit_once('\n').unwrap().0), "<input>:27:1: expected `If` for monkey 3, found end of line");
+    }
+}
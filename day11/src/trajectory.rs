@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+use crate::{modulus, Monkey, Overflow};
+
+// Where one item goes when worry is kept modulo the divisors. An item is
+// thrown the same way whatever happens to the others, so once it's back
+// with the same monkey and the same worry level at the start of a round,
+// everything it did since then repeats forever.
+#[derive(Debug, Clone)]
+pub struct Trajectory {
+    // The monkey holding the item and its worry level at the start of each
+    // round, up to the first one that repeats
+    pub states: Vec<(usize, u128)>,
+    // Round that the state after the last one is a repeat of
+    pub cycle_start: usize,
+    // Inspections of the item by each monkey during the first n rounds,
+    // for every n up to `states.len()`
+    counts: Vec<Vec<u64>>,
+}
+
+impl Trajectory {
+    // Follow item number `item`, which starts with `monkey`. Fails like
+    // `Troop::new` with `Relief::Modulo` does.
+    pub fn new(monkeys: &[Monkey], item: usize, monkey: usize, worry: u128) -> Result<Self, Overflow> {
+        let modulus = modulus(monkeys)?;
+        let mut seen = HashMap::new();
+        let mut states = Vec::new();
+        let mut counts = vec![vec![0; monkeys.len()]];
+        let (mut monkey, mut worry) = (monkey, worry);
+        loop {
+            if let Some(&cycle_start) = seen.get(&(monkey, worry)) {
+                return Ok(Trajectory { states, cycle_start, counts });
+            }
+            seen.insert((monkey, worry), states.len());
+            states.push((monkey, worry));
+
+            // Monkeys take their turns in order, so the item keeps moving
+            // this round for as long as it's thrown to later monkeys
+            let mut round = counts[counts.len() - 1].clone();
+            loop {
+                round[monkey] += 1;
+                worry = monkeys[monkey].operation.eval_modulo(&worry, &modulus)
+                    .ok_or(Overflow::Worry { round: states.len(), monkey, item })?;
+                let target = monkeys[monkey].target(&worry);
+                let later = target > monkey;
+                monkey = target;
+                if !later {
+                    break;
+                }
+            }
+            counts.push(round);
+        }
+    }
+
+    // Number of rounds after which the states repeat
+    pub fn cycle_len(&self) -> usize {
+        self.states.len() - self.cycle_start
+    }
+
+    // Inspections of the item by each monkey during the first `rounds`
+    // rounds
+    pub fn inspections(&self, rounds: u64) -> Vec<u64> {
+        let simulated = self.states.len() as u64;
+        if rounds <= simulated {
+            return self.counts[rounds as usize].clone();
+        }
+        let start = self.cycle_start as u64;
+        let cycles = (rounds - start) / self.cycle_len() as u64;
+        let rest = ((rounds - start) % self.cycle_len() as u64) as usize;
+        let (before, cycle, after) = (&self.counts[self.cycle_start], &self.counts[self.states.len()], &self.counts[self.cycle_start + rest]);
+        (0..before.len())
+            .map(|i| before[i] + cycles * (cycle[i] - before[i]) + (after[i] - before[i]))
+            .collect()
+    }
+}
+
+// Every item's trajectory, in the order the monkeys hold them at the start
+pub fn trajectories(monkeys: &[Monkey]) -> Result<Vec<Trajectory>, Overflow> {
+    monkeys.iter().enumerate()
+        .flat_map(|(idx, monkey)| monkey.items.iter().map(move |&worry| (idx, worry)))
+        .enumerate()
+        .map(|(item, (idx, worry))| Trajectory::new(monkeys, item, idx, worry))
+        .collect()
+}
+
+// Items inspected by each monkey after `rounds` rounds when worry is kept
+// modulo the divisors, worked out from each item's cycle rather than by
+// playing every round
+pub fn inspections_after(monkeys: &[Monkey], rounds: u64) -> Result<Vec<u64>, Overflow> {
+    let mut inspections = vec![0; monkeys.len()];
+    for trajectory in trajectories(monkeys)? {
+        for (total, n) in inspections.iter_mut().zip(trajectory.inspections(rounds)) {
+            *total += n;
+        }
+    }
+    Ok(inspections)
+}
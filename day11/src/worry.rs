@@ -0,0 +1,117 @@
+use std::error::Error;
+use std::fmt;
+#[cfg(feature = "bigint")]
+use num_bigint::BigUint;
+
+// Numbers that worry levels can be kept in. Arithmetic gives None when the
+// result can't be kept: it's too large, negative or a division by zero.
+pub trait Worry: Clone + PartialEq + From<u128> {
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+    fn checked_sub(&self, other: &Self) -> Option<Self>;
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
+    fn checked_div(&self, other: &Self) -> Option<Self>;
+    fn checked_rem(&self, other: &Self) -> Option<Self>;
+}
+
+impl Worry for u128 {
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        u128::checked_add(*self, *other)
+    }
+
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        u128::checked_sub(*self, *other)
+    }
+
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        u128::checked_mul(*self, *other)
+    }
+
+    fn checked_div(&self, other: &Self) -> Option<Self> {
+        u128::checked_div(*self, *other)
+    }
+
+    fn checked_rem(&self, other: &Self) -> Option<Self> {
+        u128::checked_rem(*self, *other)
+    }
+}
+
+// Never too large, so only negative results and division by zero fail
+#[cfg(feature = "bigint")]
+impl Worry for BigUint {
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(self + other)
+    }
+
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        (self >= other).then(|| self - other)
+    }
+
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        Some(self * other)
+    }
+
+    fn checked_div(&self, other: &Self) -> Option<Self> {
+        (*other != BigUint::ZERO).then(|| self / other)
+    }
+
+    fn checked_rem(&self, other: &Self) -> Option<Self> {
+        (*other != BigUint::ZERO).then(|| self % other)
+    }
+}
+
+// A number that couldn't be worked out
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Overflow {
+    // The least common multiple of the monkeys' divisors
+    Modulus,
+    // An item's worry level when a monkey inspected it
+    Worry { round: usize, monkey: usize, item: usize },
+    // Worry levels kept modulo the divisors, which this monkey's operation
+    // can't work with because it divides them
+    Division { monkey: usize },
+    // Relief that divides worry levels by 0
+    DivideByZero,
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Overflow::Modulus => write!(f, "the monkeys' divisors have no common multiple below 2^128"),
+            Overflow::Worry { round, monkey, item } => write!(
+                f,
+                "worry level of item {} went out of range when monkey {} inspected it in round {}",
+                item, monkey, round
+            ),
+            Overflow::Division { monkey } => write!(
+                f,
+                "monkey {} divides worry levels, so they can't be kept modulo the divisors",
+                monkey
+            ),
+            Overflow::DivideByZero => write!(f, "worry levels can't be divided by 0 for relief"),
+        }
+    }
+}
+
+impl Error for Overflow {}
+
+fn gcd(a: u128, b: u128) -> u128 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+// Least common multiple of every number in `numbers`
+pub fn lcm(numbers: impl IntoIterator<Item = u128>) -> Option<u128> {
+    numbers.into_iter().try_fold(1, |lcm: u128, n| (lcm / gcd(lcm, n)).checked_mul(n))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_arithmetic() {
+        assert_eq!(lcm([4, 6, 10]), Some(60));
+        assert_eq!(lcm([u128::MAX, 2]), None);
+        assert_eq!(Worry::checked_mul(&(u128::MAX / 2), &3), None);
+        assert_eq!(Worry::checked_sub(&2u128, &3), None);
+    }
+}
@@ -1,9 +1,10 @@
+use common::Solution;
+use day12::Day12;
 use std::fs;
-use day12::part1;
 
 fn main() {
     let text = fs::read_to_string("data/input.txt").unwrap();
-    println!("{}", part1(text));
+    println!("{}", Day12::part1(&Day12::parse(&text).unwrap()));
 }
 
 #[cfg(test)]
@@ -13,6 +14,6 @@ mod tests {
     #[test]
     fn example1() {
         let text = fs::read_to_string("data/part1_example.txt").unwrap();
-        assert_eq!(part1(text), 31)
+        assert_eq!(Day12::part1(&Day12::parse(&text).unwrap()), 31)
     }
 }
@@ -1,9 +1,10 @@
+use common::Solution;
+use day12::Day12;
 use std::fs;
-use day12::part2;
 
 fn main() {
     let text = fs::read_to_string("data/input.txt").unwrap();
-    println!("{}", part2(text));
+    println!("{}", Day12::part2(&Day12::parse(&text).unwrap()));
 }
 
 #[cfg(test)]
@@ -13,6 +14,6 @@ mod tests {
     #[test]
     fn example2() {
         let text = fs::read_to_string("data/part2_example.txt").unwrap();
-        assert_eq!(part2(text), 29)
+        assert_eq!(Day12::part2(&Day12::parse(&text).unwrap()), 29)
     }
 }
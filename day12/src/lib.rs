@@ -1,150 +1,93 @@
-use glam::u32::UVec2;
+use common::{parse, ParseError, Solution};
+use grid::{Grid, Pos};
 use pathfinding::directed::dijkstra::dijkstra;
-use std::collections::hash_map::HashMap;
 
-#[derive(Debug)]
-struct HeightMap {
-    start: UVec2,
-    end: UVec2,
-    map: HashMap<UVec2, u32>,
-}
+pub struct Day12;
 
-fn parse_map(text: String) -> HeightMap {
-    let mut map = HeightMap {
-        start: UVec2::ZERO,
-        end: UVec2::ZERO,
-        map: HashMap::new(),
-    };
-    for (y, line) in text.lines().enumerate() {
-        for (x, c) in line.chars().enumerate() {
-            let x = x as u32;
-            let y = y as u32;
-            match c {
-                'S' => {
-                    map.start = UVec2 { x, y };
-                    map.map.insert(UVec2 { x, y }, 0)
-                }
-                'E' => {
-                    map.end = UVec2 { x, y };
-                    map.map.insert(UVec2 { x, y }, 25)
-                }
-                c => map.map.insert(UVec2 { x, y }, c as u32 - 97),
-            };
-        }
+impl Solution for Day12 {
+    type Input = HeightMap;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(text: &str) -> Result<HeightMap, ParseError> {
+        parse_map(text)
     }
-    map
-}
 
-pub fn part1(text: String) -> u32 {
-    let map = parse_map(text);
-    let result = dijkstra(
-        &map.start,
-        |p| {
-            let height = *map.map.get(p).unwrap();
-            let adj_nodes = [
-                p.saturating_add(UVec2::new(1, 0)),
-                p.saturating_add(UVec2::new(0, 1)),
-                p.saturating_sub(UVec2::new(1, 0)),
-                p.saturating_sub(UVec2::new(0, 1)),
-            ];
-            adj_nodes
-                .into_iter()
-                .filter_map(|adj_node| {
-                    if &adj_node == p {
-                        return None;
-                    }
-                    if !map.map.contains_key(&adj_node) {
-                        return None;
-                    }
-                    if map.map.get(&adj_node).unwrap().saturating_sub(height) <= 1 {
-                        Some((adj_node, 1))
-                    } else {
-                        None
-                    }
-                })
-                .collect::<Vec<(UVec2, u32)>>()
-        },
-        |p| p == &map.end,
-    );
-    result.expect("No path found").1
+    fn part1(map: &HeightMap) -> u32 {
+        shortest_path(map, &map.start).expect("No path found")
+    }
+
+    fn part2(map: &HeightMap) -> u32 {
+        let mut min_steps = u32::MAX;
+        for start in map.lowest.iter() {
+            let num_steps = shortest_path(map, start).unwrap_or(u32::MAX);
+            if num_steps < min_steps {
+                min_steps = num_steps;
+            }
+        }
+        min_steps
+    }
 }
 
 #[derive(Debug)]
-struct HeightMap2 {
-    start: Vec<UVec2>,
-    end: UVec2,
-    map: HashMap<UVec2, u32>,
+pub struct HeightMap {
+    start: Pos,
+    lowest: Vec<Pos>,
+    end: Pos,
+    map: Grid<u32>,
 }
 
-fn parse_map2(text: String) -> HeightMap2 {
-    let mut map = HeightMap2 {
-        start: Vec::new(),
-        end: UVec2::ZERO,
-        map: HashMap::new(),
-    };
-    for (y, line) in text.lines().enumerate() {
-        for (x, c) in line.chars().enumerate() {
-            let x = x as u32;
-            let y = y as u32;
-            match c {
-                'S' => {
-                    map.start.push(UVec2 { x, y });
-                    map.map.insert(UVec2 { x, y }, 0);
-                }
-                'E' => {
-                    map.end = UVec2 { x, y };
-                    map.map.insert(UVec2 { x, y }, 25);
-                }
-                c => {
-                    let height = c as u32 - 97;
-                    if height == 0 {
-                        map.start.push(UVec2 { x, y });
-                    }
-                    map.map.insert(UVec2 { x, y }, c as u32 - 97);
-                }
-            };
+fn parse_map(text: &str) -> Result<HeightMap, ParseError> {
+    let marks = Grid::parse(text, "a height from `a` to `z`, `S` or `E`", |c| {
+        (c.is_ascii_lowercase() || c == 'S' || c == 'E').then_some(c)
+    })?;
+    let (mut start, mut end) = (None, None);
+    for ((x, y), &c) in marks.iter() {
+        let duplicate = match c {
+            'S' => start.replace((x, y)).is_some(),
+            'E' => end.replace((x, y)).is_some(),
+            _ => false,
+        };
+        if duplicate {
+            let found = c.to_string();
+            return Err(ParseError::new(y + 1, x + 1, &format!("only one `{}`", c), Some(&found)));
         }
     }
-    map
+    let start = start.ok_or_else(|| parse::unexpected_end(text, "a start position `S`"))?;
+    let end = end.ok_or_else(|| parse::unexpected_end(text, "a best signal position `E`"))?;
+
+    let heights: Vec<u32> = marks.iter()
+        .map(|(_, &c)| match c {
+            'S' => 0,
+            'E' => 25,
+            c => c as u32 - 97,
+        })
+        .collect();
+    let map = Grid::from_cells(marks.width(), heights);
+    let lowest = map.iter()
+        .filter(|(_, &height)| height == 0)
+        .map(|(pos, _)| pos)
+        .collect();
+    Ok(HeightMap {
+        start,
+        lowest,
+        end,
+        map,
+    })
 }
 
-pub fn part2(text: String) -> u32 {
-    let map = parse_map2(text);
-    let mut min_steps = u32::MAX;
-    for start in map.start.iter() {
-        let result = dijkstra(
-            start,
-            |p| {
-                let height = *map.map.get(p).unwrap();
-                let adj_nodes = [
-                    p.saturating_add(UVec2::new(1, 0)),
-                    p.saturating_add(UVec2::new(0, 1)),
-                    p.saturating_sub(UVec2::new(1, 0)),
-                    p.saturating_sub(UVec2::new(0, 1)),
-                ];
-                adj_nodes
-                    .into_iter()
-                    .filter_map(|adj_node| {
-                        if &adj_node == p {
-                            return None;
-                        }
-                        if !map.map.contains_key(&adj_node) {
-                            return None;
-                        }
-                        if map.map.get(&adj_node).unwrap().saturating_sub(height) <= 1 {
-                            Some((adj_node, 1))
-                        } else {
-                            None
-                        }
-                    })
-                    .collect::<Vec<(UVec2, u32)>>()
-            },
-            |p| p == &map.end,
-        );
-        let num_steps = result.or_else(|| Some((Vec::new(), u32::MAX))).unwrap().1;
-        if num_steps < min_steps {
-            min_steps = num_steps;
-        }
-    }
-    min_steps
+fn shortest_path(map: &HeightMap, start: &Pos) -> Option<u32> {
+    let result = dijkstra(
+        start,
+        |&p| {
+            let height = map.map[p];
+            map.map
+                .neighbours4(p)
+                .filter(|&adj_node| map.map[adj_node].saturating_sub(height) <= 1)
+                .map(|adj_node| (adj_node, 1))
+                .collect::<Vec<(Pos, u32)>>()
+        },
+        |p| p == &map.end,
+    );
+    result.map(|(_, steps)| steps)
 }
@@ -0,0 +1,170 @@
+use std::str::CharIndices;
+use std::cmp::{Ordering, min};
+use std::iter::Peekable;
+use common::{parse, ParseError, Solution};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Packet {
+    List(Vec<Packet>),
+    Int(u32),
+    EndBracket,
+    Empty
+}
+
+impl Packet {
+    fn new(line: &parse::Line, char_iter: &mut Peekable<CharIndices>) -> Result<Self, ParseError> {
+        let c = char_iter.next();
+        let packet = match c {
+            Some((_, '[')) => {
+                let mut list = Vec::new();
+                loop {
+                    if char_iter.peek().is_none() {
+                        return Err(line.error_at_end("`]`"));
+                    }
+                    let next_item = Packet::new(line, char_iter)?;
+                    if next_item == Packet::Empty {
+                        continue;
+                    }
+                    if next_item == Packet::EndBracket {
+                        break;
+                    }
+                    list.push(next_item);
+                }
+                Packet::List(list)
+            },
+            Some((_, ']')) => Packet::EndBracket,
+            Some((_, ',')) => Packet::Empty,
+            Some((start, _)) => {
+                let mut end = line.text.len();
+                loop {
+                    match char_iter.peek() {
+                        Some((_, ',')) => {
+                            end = char_iter.next().unwrap().0;
+                            break;
+                        },
+                        Some((idx, ']')) => {
+                            end = *idx;
+                            break;
+                        },
+                        Some(_) => {
+                            char_iter.next();
+                        },
+                        None => break
+                    }
+                }
+                Packet::Int(line.parse::<u32>(&line.text[start..end], "a number or a list")?)
+            },
+            None => Packet::Empty
+        };
+        Ok(packet)
+    }
+
+    // A whole line holds exactly one list
+    fn from_line(line: &parse::Line) -> Result<Self, ParseError> {
+        if !line.text.starts_with('[') {
+            return Err(line.error_at(line.text, "a list"));
+        }
+        let mut char_iter = line.text.char_indices().peekable();
+        let packet = Packet::new(line, &mut char_iter)?;
+        match char_iter.next() {
+            Some((idx, _)) => Err(line.error_at(&line.text[idx..], "end of line")),
+            None => Ok(packet),
+        }
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Packet::Int(a), Packet::Int(b)) => a.cmp(b),
+            (Packet::Int(a), Packet::List(_)) => {
+                let left = Packet::List(vec![Packet::Int(*a)]);
+                left.cmp(other)
+            },
+            (Packet::List(_), Packet::Int(b)) => {
+                let right = Packet::List(vec![Packet::Int(*b)]);
+                self.cmp(&right)
+            },
+            (Packet::List(a), Packet::List(b)) => {
+                let max_idx = min(a.len(), b.len());
+                for idx in 0..max_idx {
+                    match a[idx].cmp(&b[idx]) {
+                        Ordering::Equal => continue,
+                        other => return other
+                    }
+                }
+                a.len().cmp(&b.len())
+            }
+            _ => panic!()
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    // All packets in the order they were received
+    type Input = Vec<Packet>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        let packets = parse_input(text)?;
+        if packets.len() % 2 != 0 {
+            return Err(parse::unexpected_end(text, "a second packet to complete the pair"));
+        }
+        Ok(packets)
+    }
+
+    // Part 1: sum of the indices of the pairs already in the right order
+    fn part1(packets: &Self::Input) -> usize {
+        let mut sum = 0;
+        for (idx, pair) in packets.chunks(2).enumerate() {
+            if pair[0] < pair[1] {
+                sum += idx + 1;
+            }
+        }
+        sum
+    }
+
+    // Part 2: decoder key from the positions of the divider packets
+    fn part2(packets: &Self::Input) -> usize {
+        let mut packets = packets.clone();
+
+        // Divider packets
+        packets.push(Packet::List(vec!(Packet::List(vec!(Packet::Int(2))))));
+        packets.push(Packet::List(vec!(Packet::List(vec!(Packet::Int(6))))));
+
+        packets.sort();
+        let idx1 = packets.binary_search(
+            &Packet::List(vec!(Packet::List(vec!(Packet::Int(2))))))
+            .unwrap();
+        let idx2 = packets.binary_search(
+            &Packet::List(vec!(Packet::List(vec!(Packet::Int(6))))))
+            .unwrap();
+        (idx1+1)*(idx2+1)
+    }
+}
+
+fn parse_input(input_str: &str) -> Result<Vec<Packet>, ParseError> {
+    let mut data = Vec::new();
+    for line in parse::lines(input_str) {
+        match line.text.len() {
+            0 => {
+                continue;
+            },
+            _ => {
+                let packet = Packet::from_line(&line)?;
+                data.push(packet);
+            }
+        }
+    }
+
+    Ok(data)
+}
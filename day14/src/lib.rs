@@ -0,0 +1,232 @@
+use std::fmt;
+use std::io;
+use std::cmp::{min, max};
+use common::{parse, ParseError, Solution};
+use common::visual::{Visualize, Visualizer};
+use grid::OffsetGrid;
+
+// Where the sand pours in
+const SOURCE: (isize, isize) = (500, 0);
+
+#[derive(Debug, Clone)]
+pub struct Scan {
+    grid: OffsetGrid<Tile>,
+    // The floor is endless, so it isn't stored in the grid
+    floor: isize,
+}
+
+impl Scan {
+    fn get(&self, x: isize, y: isize) -> Tile {
+        if y == self.floor {
+            Tile::Rock
+        } else {
+            *self.grid.get((x, y))
+        }
+    }
+
+    // Drop a grain of sand from the source and return where it comes to rest
+    fn insert_sand(&mut self) -> (isize, isize) {
+        let mut pos = SOURCE;
+        'falling: loop {
+            let (x, y) = pos;
+            for dx in [0, -1, 1] {
+                if self.get(x + dx, y + 1) == Tile::Air {
+                    pos = (x + dx, y + 1);
+                    continue 'falling;
+                }
+            }
+            self.grid.set(pos, Tile::Sand);
+            return pos;
+        }
+    }
+}
+
+impl fmt::Display for Scan {
+    // Columns with anything other than air in them, and rows from the top
+    // one with anything in it down to the floor. The grid can store rows
+    // past the floor, which are never drawn.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let filled = || {
+            self.grid.y_range()
+                .filter(|&y| y < self.floor)
+                .flat_map(|y| self.grid.x_range().map(move |x| (x, y)))
+                .filter(|&(x, y)| self.get(x, y) != Tile::Air)
+        };
+        let x_min = filled().map(|(x, _)| x).min().unwrap_or(SOURCE.0);
+        let x_max = filled().map(|(x, _)| x).max().unwrap_or(SOURCE.0);
+        let y_min = filled().map(|(_, y)| y).min().unwrap_or(SOURCE.1);
+        for y in y_min..=self.floor {
+            let row: String = (x_min..=x_max)
+                .map(|x| match self.get(x, y) {
+                    Tile::Air => '.',
+                    Tile::Rock => '#',
+                    Tile::Sand => 'o',
+                    Tile::Start => '+'
+                })
+                .collect();
+            writeln!(f, "{}", row)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Tile {
+    Air,
+    Rock,
+    Sand,
+    Start
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    // Cave slice with the rock paths drawn in
+    type Input = Scan;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        read_scan(text)
+    }
+
+    // Part 1: sand that comes to rest before any falls past the lowest rock
+    fn part1(scan: &Self::Input) -> u32 {
+        let mut scan = scan.clone();
+        let abyss = scan.floor - 1;
+
+        let mut num_sand = 0;
+        while scan.insert_sand().1 != abyss {
+            num_sand += 1;
+        }
+        num_sand
+    }
+
+    // Part 2: sand that comes to rest on the floor until the source is blocked
+    fn part2(scan: &Self::Input) -> u32 {
+        let mut scan = scan.clone();
+
+        let mut num_sand = 1;
+        while scan.insert_sand() != SOURCE {
+            num_sand += 1;
+        }
+        num_sand
+    }
+}
+
+impl Visualize for Day14 {
+    fn visualize(scan: &Self::Input, part: u8, visualizer: &mut Visualizer) -> io::Result<()> {
+        let mut scan = scan.clone();
+        let abyss = scan.floor - 1;
+        while !visualizer.stopped() {
+            let rest = scan.insert_sand();
+            visualizer.frame(|| scan.to_string())?;
+            if (part == 1 && rest.1 == abyss) || rest == SOURCE {
+                break;
+            }
+        }
+        visualizer.finish(|| scan.to_string())
+    }
+}
+
+fn read_scan(input_str: &str) -> Result<Scan, ParseError> {
+    let paths = parse::lines(input_str)
+        .map(|line| parse_path(&line))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut grid = OffsetGrid::new(Tile::Air);
+    grid.set(SOURCE, Tile::Start);
+    for path in paths.iter() {
+        for segment in path.windows(2) {
+            let (x_prev, y_prev) = segment[0];
+            let (x, y) = segment[1];
+            if x == x_prev {
+                for y2 in min(y, y_prev)..=max(y, y_prev) {
+                    grid.set((x, y2), Tile::Rock);
+                }
+            } else {
+                for x2 in min(x, x_prev)..=max(x, x_prev) {
+                    grid.set((x2, y), Tile::Rock);
+                }
+            }
+        }
+    }
+
+    // The floor is two below the lowest rock
+    let y_max = paths.iter().flatten().map(|&(_, y)| y).max().unwrap_or(0);
+    Ok(Scan {
+        grid,
+        floor: y_max + 2,
+    })
+}
+
+// Rock paths are straight lines between the points `x,y -> x,y -> ...`
+fn parse_path(line: &parse::Line) -> Result<Vec<(isize, isize)>, ParseError> {
+    let mut path = Vec::new();
+    for coordinate in line.text.split(" -> ") {
+        let (x, y) = parse_coord(line, coordinate)?;
+        if let Some(&(x_prev, y_prev)) = path.last() {
+            if x != x_prev && y != y_prev {
+                return Err(line.error_at(coordinate, "a point in line with the previous one"));
+            }
+        }
+        path.push((x, y));
+    }
+    Ok(path)
+}
+
+fn parse_coord(line: &parse::Line, input_str: &str) -> Result<(isize, isize), ParseError> {
+    let (x, y) = input_str.split_once(',')
+        .ok_or_else(|| line.error_at(input_str, "a point like `498,4`"))?;
+    let x = line.parse::<usize>(x, "an x coordinate")?;
+    let y = line.parse::<usize>(y, "a y coordinate")?;
+    Ok((x as isize, y as isize))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rendered_like_the_puzzle() {
+        let scan = Day14::parse(include_str!("../test.txt")).unwrap();
+
+        // Part 1 after 24 units of sand, with the floor part 1 ignores
+        let mut part1 = scan.clone();
+        for _ in 0..24 {
+            part1.insert_sand();
+        }
+        assert_eq!(part1.to_string(), "\
+......+...
+..........
+......o...
+.....ooo..
+....#ooo##
+...o#ooo#.
+..###ooo#.
+....oooo#.
+.o.ooooo#.
+#########.
+..........
+##########
+");
+
+        // The puzzle's picture has two more columns of air on either side
+        let mut part2 = scan;
+        while part2.insert_sand() != SOURCE {}
+        assert_eq!(part2.to_string(), "\
+..........o..........
+.........ooo.........
+........ooooo........
+.......ooooooo.......
+......oo#ooo##o......
+.....ooo#ooo#ooo.....
+....oo###ooo#oooo....
+...oooo.oooo#ooooo...
+..oooooooooo#oooooo..
+.ooo#########ooooooo.
+ooooo.......ooooooooo
+#####################
+");
+    }
+}
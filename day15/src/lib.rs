@@ -0,0 +1,212 @@
+use regex::Regex;
+use std::collections::{HashSet, HashMap};
+use common::{parse, ParseError, Solution};
+use common::geometry::{Bounds2, Point2, Vector2};
+
+type Point = Point2<i128>;
+
+// The row part 1 looks at and the largest coordinate of the area part 2
+// searches, as the puzzle gives them for everyone's input
+const ROW: i128 = 2000000;
+const SEARCH_MAX: i128 = 4000000;
+
+#[derive(Debug)]
+pub struct SparseScan {
+    sensor2beacon: HashMap<Point, Point>,
+    beacons: HashSet<Point>,
+    x_min: i128,
+    x_max: i128,
+    // Row y=row is the one part 1 counts in
+    pub row: i128,
+    // Part 2 searches x and y from 0 to search_max
+    pub search_max: i128,
+}
+
+impl SparseScan {
+    fn new(x_min: i128, x_max: i128) -> Self {
+        Self {
+            sensor2beacon: HashMap::new(),
+            beacons: HashSet::new(),
+            x_min,
+            x_max,
+            row: ROW,
+            search_max: SEARCH_MAX,
+        }
+    }
+
+    fn is_covered(&self, p: Point) -> bool {
+        for (sensor, beacon) in self.sensor2beacon.iter() {
+            if p.manhattan_distance(*sensor) <= sensor.manhattan_distance(*beacon) {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn get_potential_beacons_in_row(&self, y: i128, min: i128, max: i128, include_beacons: bool) -> Vec<Point> {
+        let mut potentials = Vec::new();
+        for x in min..=max {
+            let p = Point::new(x, y);
+            if self.beacons.contains(&p) {
+                if include_beacons {
+                    potentials.push(p);
+                }
+                continue;
+            }
+            if !self.is_covered(p) {
+                potentials.push(p);
+            }
+        }
+        potentials
+    }
+
+    fn get_covered_in_row(&self, y: i128) -> u128 {
+        let beacons = self.get_potential_beacons_in_row(y, self.x_min, self.x_max, true);
+        (self.x_max - self.x_min + 1) as u128 - beacons.len() as u128
+    }
+
+    fn find_beacon(&self, area: Bounds2<i128>) -> Option<Point> {
+        let Bounds2 { min: p_min, max: p_max } = area;
+        let w = p_max.x - p_min.x;
+        let h = p_max.y - p_min.y;
+
+        // Check if search region is contained by a single sensor neighborhood
+        for (sensor, beacon) in self.sensor2beacon.iter() {
+            let d = sensor.manhattan_distance(*beacon);
+            if area.corners().iter().all(|c| c.manhattan_distance(*sensor) <= d) {
+                return None;
+            }
+        }
+
+        // If the search region is small enough, check individual tiles
+        if p_min.manhattan_distance(p_max) <= 1 {
+            for i in p_min.x..=p_max.x {
+                for j in p_min.y..=p_max.y {
+                    let p = Point::new(i, j);
+                    if !self.is_covered(Point::new(i, j)) {
+                        return Some(p);
+                    }
+                }
+            }
+            return None;
+        }
+
+        // Break up the search region into 4 smaller regions
+        let quads = vec![
+            (p_min, p_min + Vector2::new(w/2, h/2)), // top left
+            (p_min + Vector2::new(w/2 + 1, 0), p_min + Vector2::new(w, h/2)), // top right
+            (p_min + Vector2::new(0, h/2 + 1), p_min + Vector2::new(w/2, h)), // bot left
+            (p_min + Vector2::new(w/2 + 1, h/2 + 1), p_max) // bot right
+        ];
+        for quad in quads {
+            match self.find_beacon(Bounds2::new(quad.0, quad.1)) {
+                Some(p) => return Some(p),
+                None => continue
+            }
+        }
+        None
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    // Sensors and the closest beacon each of them detected
+    type Input = SparseScan;
+    type Answer1 = u128;
+    type Answer2 = i128;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        read_scan(text)
+    }
+
+    // Part 1: positions in row y=2000000 that cannot contain a beacon
+    fn part1(scan: &Self::Input) -> u128 {
+        scan.get_covered_in_row(scan.row)
+    }
+
+    // Part 2: tuning frequency of the only uncovered position in the search area
+    fn part2(scan: &Self::Input) -> i128 {
+        let area = Bounds2::new(Point::zero(), Point::new(scan.search_max, scan.search_max));
+        let beacon = scan.find_beacon(area).unwrap();
+        beacon.x*4000000 + beacon.y
+    }
+}
+
+// The row and search area aren't part of the puzzle input, and the example
+// uses smaller ones than the real puzzle. They can be set on lines before
+// the sensors, like `row 10` and `area 20`.
+fn read_scan(input_str: &str) -> Result<SparseScan, ParseError> {
+    let re = Regex::new(r"^Sensor at x=(-?\d+), y=(-?\d+): closest beacon is at x=(-?\d+), y=(-?\d+)$").unwrap();
+    let mut lines = parse::lines(input_str).peekable();
+    let (mut row, mut search_max) = (ROW, SEARCH_MAX);
+    while let Some(line) = lines.next_if(|line| !line.text.starts_with("Sensor")) {
+        let mut tokens = line.tokens();
+        match tokens.next_token("`row`, `area` or a sensor")? {
+            "row" => row = tokens.parse::<i128>("a y coordinate")?,
+            "area" => search_max = tokens.parse::<i128>("a largest coordinate")?,
+            token => return Err(line.error_at(token, "`row`, `area` or a sensor")),
+        }
+        tokens.end()?;
+    }
+    let pairs = lines
+        .map(|line| parse_coords(&re, &line))
+        .collect::<Result<Vec<_>, _>>()?;
+    let (x_min, x_max) = find_bounds(&pairs);
+
+    let mut scan = SparseScan::new(x_min, x_max);
+    scan.row = row;
+    scan.search_max = search_max;
+    for (sensor, beacon) in pairs {
+        scan.sensor2beacon.insert(sensor, beacon);
+        scan.beacons.insert(beacon);
+    }
+
+    Ok(scan)
+}
+
+fn find_bounds(pairs: &[(Point, Point)]) -> (i128, i128) {
+    let (mut x_min, mut x_max) = (i128::MAX, i128::MIN);
+
+    for &(sensor, beacon) in pairs {
+        let d = sensor.manhattan_distance(beacon);
+        if sensor.x - d < x_min {
+            x_min = sensor.x - d;
+        }
+        if sensor.x + d > x_max {
+            x_max = sensor.x + d;
+        }
+    }
+
+    (x_min, x_max)
+}
+
+// Sensor and beacon positions of one report line
+fn parse_coords(re: &Regex, line: &parse::Line) -> Result<(Point, Point), ParseError> {
+    let cap = re.captures(line.text).ok_or_else(|| line.error_at(
+        line.text,
+        "a line like `Sensor at x=2, y=18: closest beacon is at x=-2, y=15`"
+    ))?;
+    let coord = |i: usize| line.parse::<i128>(cap.get(i).unwrap().as_str(), "a coordinate");
+    Ok((Point::new(coord(1)?, coord(2)?), Point::new(coord(3)?, coord(4)?)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn settings() {
+        let sensor = "Sensor at x=8, y=7: closest beacon is at x=2, y=10\n";
+        let scan = Day15::parse(sensor).unwrap();
+        assert_eq!((scan.row, scan.search_max), (ROW, SEARCH_MAX));
+        let scan = Day15::parse(&format!("area 20\nrow 10\n{}", sensor)).unwrap();
+        assert_eq!((scan.row, scan.search_max), (10, 20));
+        assert_eq!(Day15::part1(&scan), 12);
+
+        let err = Day15::parse(&format!("rows 10\n{}", sensor)).unwrap_err();
+        assert_eq!(err.to_string(), "<input>:1:1: expected `row`, `area` or a sensor, found `rows`");
+        let err = Day15::parse(&format!("row 10 20\n{}", sensor)).unwrap_err();
+        assert_eq!(err.to_string(), "<input>:1:8: expected end of line, found `20`");
+    }
+}
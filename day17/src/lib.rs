@@ -0,0 +1,574 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::collections::HashMap;
+use common::{parse, ParseError, Solution};
+use common::visual::{Visualize, Visualizer};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Jet {
+    Left,
+    Right,
+}
+
+// The jet pattern is one line of `<` and `>`. Anything else, including a
+// stray `\r` or space, is an error rather than the end of the pattern.
+pub fn parse_jets(text: &str) -> Result<Vec<Jet>, ParseError> {
+    let pattern = text.strip_suffix('\n').unwrap_or(text);
+    let mut jets = Vec::new();
+    for (column, c) in pattern.chars().enumerate() {
+        let jet = match c {
+            '<' => Jet::Left,
+            '>' => Jet::Right,
+            c => {
+                let found = c.escape_debug().to_string();
+                return Err(ParseError::new(1, column + 1, "a jet `<` or `>`", Some(&found)));
+            }
+        };
+        jets.push(jet);
+    }
+    if jets.is_empty() {
+        return Err(ParseError::new(1, 1, "a jet `<` or `>`", None));
+    }
+    Ok(jets)
+}
+
+// Each row of a chamber is a u8 bitmask, so it can't be wider than this
+pub const MAX_WIDTH: u32 = 8;
+
+// The shapes of the puzzle, in the order they fall
+const STANDARD_SHAPES: &str = include_str!("../shapes.txt");
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shape {
+    // Rows of the shape from the bottom up, with bit 0 as the left-most
+    // unit, so a rock at x is its rows shifted left by x
+    rows: Vec<u8>,
+    width: u32,
+}
+
+impl Shape {
+    pub fn standard() -> Vec<Shape> {
+        parse_shapes(STANDARD_SHAPES).expect("the standard shapes are valid")
+    }
+}
+
+// Shapes drawn with `#` for rock and `.` for air, separated by blank
+// lines. A shape is anchored at the bottom left of its drawing, with
+// empty rows and columns around it trimmed off.
+pub fn parse_shapes(text: &str) -> Result<Vec<Shape>, ParseError> {
+    let mut blocks = vec![Vec::new()];
+    for line in parse::lines(text) {
+        match blocks.last_mut() {
+            Some(block) if !line.text.trim().is_empty() => block.push(line),
+            _ => blocks.push(Vec::new()),
+        }
+    }
+    let shapes = blocks.iter()
+        .filter(|block| !block.is_empty())
+        .map(|block| parse_shape(block))
+        .collect::<Result<Vec<_>, _>>()?;
+    if shapes.is_empty() {
+        return Err(parse::unexpected_end(text, "a shape"));
+    }
+    Ok(shapes)
+}
+
+fn parse_shape(lines: &[parse::Line]) -> Result<Shape, ParseError> {
+    // Drawn from the top down, stored from the bottom up
+    let mut rows = Vec::new();
+    for line in lines.iter().rev() {
+        let mut row = 0u8;
+        for (x, (idx, c)) in line.text.char_indices().enumerate() {
+            let part = &line.text[idx..idx + c.len_utf8()];
+            match c {
+                '#' if x as u32 >= MAX_WIDTH => {
+                    return Err(line.error_at(part, &format!("a shape at most {} units wide", MAX_WIDTH)));
+                }
+                '#' => row |= 1 << x,
+                '.' => (),
+                _ => return Err(line.error_at(part, "`#` or `.`")),
+            }
+        }
+        rows.push(row);
+    }
+
+    let all = rows.iter().fold(0, |all, row| all | row);
+    if all == 0 {
+        return Err(lines[0].error_at(lines[0].text, "a shape with at least one `#`"));
+    }
+    while rows.last() == Some(&0) {
+        rows.pop();
+    }
+    let bottom = rows.iter().position(|&row| row != 0).unwrap();
+    let left = all.trailing_zeros();
+    Ok(Shape {
+        rows: rows[bottom..].iter().map(|row| row >> left).collect(),
+        width: u8::BITS - all.leading_zeros() - left,
+    })
+}
+
+// How the chamber is laid out and what falls into it
+#[derive(Debug, Clone)]
+pub struct Config {
+    // Units between the walls, at most MAX_WIDTH
+    pub width: u32,
+    // Units between the left wall and a new rock
+    pub spawn_x: u32,
+    // Empty rows between the top of the tower and a new rock
+    pub spawn_gap: u32,
+    // Shapes in the order they fall, repeating
+    pub shapes: Vec<Shape>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            width: 7,
+            spawn_x: 2,
+            spawn_gap: 3,
+            shapes: Shape::standard(),
+        }
+    }
+}
+
+impl Config {
+    // Whether rocks can be dropped into the chamber at all
+    pub fn check(&self) -> Result<(), ChamberError> {
+        if !(1..=MAX_WIDTH).contains(&self.width) {
+            return Err(ChamberError::Width(self.width));
+        }
+        if self.shapes.is_empty() {
+            return Err(ChamberError::NoShapes);
+        }
+        for (idx, shape) in self.shapes.iter().enumerate() {
+            if shape.width > self.width.saturating_sub(self.spawn_x) {
+                return Err(ChamberError::ShapeTooWide { shape: idx, width: shape.width });
+            }
+        }
+        Ok(())
+    }
+}
+
+// Why a chamber couldn't be set up
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChamberError {
+    Jets(ParseError),
+    // Units between the walls
+    Width(u32),
+    NoShapes,
+    // A shape, counting from 0, that doesn't fit between spawn_x and the
+    // right wall
+    ShapeTooWide { shape: usize, width: u32 },
+}
+
+impl fmt::Display for ChamberError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ChamberError::Jets(err) => write!(f, "{}", err),
+            ChamberError::Width(width) => {
+                write!(f, "a chamber has to be 1 to {} units wide, not {}", MAX_WIDTH, width)
+            }
+            ChamberError::NoShapes => write!(f, "a chamber needs at least one shape"),
+            ChamberError::ShapeTooWide { shape, width } => write!(
+                f,
+                "shape {} is {} units wide and doesn't fit between where rocks appear and the right wall",
+                shape, width
+            ),
+        }
+    }
+}
+
+impl Error for ChamberError {}
+
+impl From<ParseError> for ChamberError {
+    fn from(err: ParseError) -> Self {
+        ChamberError::Jets(err)
+    }
+}
+
+// Coordinates:
+// x: left-most unit is @ 0, right-most unit is @ width-1
+// y: 0 is the bottom layer above the floor, increasing upwards
+#[derive(Debug, Clone, Copy)]
+struct Rock {
+    pos: (u32, u32),
+    // Index into the chamber's shapes
+    shape: usize,
+}
+
+impl Rock {
+    // The rock's row masks together with the chamber row each one is in
+    fn rows<'a>(&self, shape: &'a Shape) -> impl Iterator<Item = (usize, u8)> + 'a {
+        let (x, y) = self.pos;
+        shape.rows.iter().enumerate().map(move |(dy, row)| (y as usize + dy, row << x))
+    }
+}
+
+#[derive(Debug)]
+pub struct Chamber {
+    jets: Vec<Jet>,
+    // Index of the jet that pushes next, wrapping around to the start
+    next_jet: usize,
+    // Rocks created so far, which also picks the next shape
+    rocks: u64,
+    width: u32,
+    spawn: (u32, u32),
+    shapes: Vec<Shape>,
+    // Settled rock, one bit per unit with bit 0 at the left wall.
+    // Row 0 is the bottom layer, rows are added as the tower grows.
+    rows: Vec<u8>,
+    height: u32,
+    // Height of the whole cycles `height_after` skipped, which isn't in
+    // `rows`
+    skipped_height: u64,
+    // Only kept to be drawn
+    falling: Option<Rock>,
+}
+
+impl Chamber {
+    pub fn new(jet_string: &str) -> Result<Self, ParseError> {
+        Ok(Chamber::from_jets(parse_jets(jet_string)?, Config::default()))
+    }
+
+    pub fn with_config(jet_string: &str, config: Config) -> Result<Self, ChamberError> {
+        config.check()?;
+        Ok(Chamber::from_jets(parse_jets(jet_string)?, config))
+    }
+
+    // Only for configs known to be valid, like the default one
+    fn from_jets(jets: Vec<Jet>, config: Config) -> Self {
+        if let Err(err) = config.check() {
+            panic!("{}", err);
+        }
+        Chamber {
+            jets,
+            next_jet: 0,
+            rocks: 0,
+            width: config.width,
+            spawn: (config.spawn_x, config.spawn_gap),
+            shapes: config.shapes,
+            rows: Vec::new(),
+            height: 0,
+            skipped_height: 0,
+            falling: None,
+        }
+    }
+
+    fn next_shape(&self) -> usize {
+        (self.rocks % self.shapes.len() as u64) as usize
+    }
+
+    // Rock is created spawn_x units away from the left wall
+    // and with spawn_gap empty rows below it down to the highest rock
+    // Rock position is anchored in bottom left corner
+    fn create_rock(&mut self) -> Rock {
+        let shape = self.next_shape();
+        self.rocks += 1;
+        Rock {
+            pos: (self.spawn.0, self.height + self.spawn.1),
+            shape
+        }
+    }
+
+    // Whether the rock fits between the walls without overlapping
+    // settled rock. Positions left of the wall or under the floor can't be
+    // represented, so moves there are caught before the rock is built.
+    fn fits(&self, rock: &Rock) -> bool {
+        let shape = &self.shapes[rock.shape];
+        rock.pos.0 + shape.width <= self.width
+            && rock.rows(shape).all(|(y, row)| self.rows.get(y).is_none_or(|settled| settled & row == 0))
+    }
+
+    fn settle(&mut self, rock: &Rock) {
+        let shape = &self.shapes[rock.shape];
+        for (y, row) in rock.rows(shape) {
+            if y >= self.rows.len() {
+                self.rows.resize(y + 1, 0);
+            }
+            self.rows[y] |= row;
+        }
+        self.height = self.height.max(rock.pos.1 + shape.rows.len() as u32);
+    }
+
+    // Update the rock position to be the next position
+    // (after being pushed by the jet and falling)
+    // If it can't fall any further it comes to rest, return false
+    // Otherwise, return true
+    fn move_rock(&mut self, rock: &mut Rock) -> bool {
+        // Being pushed into a wall or another rock does nothing
+        let jet = &self.jets[self.next_jet];
+        self.next_jet = (self.next_jet + 1) % self.jets.len();
+        let pushed = match jet {
+            Jet::Left => rock.pos.0.checked_sub(1),
+            Jet::Right => Some(rock.pos.0 + 1),
+        };
+        if let Some(x) = pushed {
+            let pushed = Rock { pos: (x, rock.pos.1), ..*rock };
+            if self.fits(&pushed) {
+                *rock = pushed;
+            }
+        }
+
+        let fallen = rock.pos.1.checked_sub(1).map(|y| Rock { pos: (rock.pos.0, y), ..*rock });
+        match fallen {
+            Some(fallen) if self.fits(&fallen) => {
+                *rock = fallen;
+                self.falling = Some(*rock);
+                true
+            }
+            _ => {
+                self.settle(rock);
+                self.falling = None;
+                false
+            }
+        }
+    }
+
+    // Let a new rock fall until it comes to rest
+    pub fn drop_rock(&mut self) {
+        let mut rock = self.create_rock();
+        while self.move_rock(&mut rock) {}
+    }
+
+    // Everything that decides how the next rocks fall: the next shape and
+    // jet, and how far down from the top each column is first blocked
+    fn state(&self) -> State {
+        let top = &self.rows[..self.height as usize];
+        let surface = (0..self.width)
+            .map(|x| {
+                let depth = top.iter()
+                    .rev()
+                    .take(SURFACE_DEPTH)
+                    .position(|row| row & (1 << x) != 0);
+                depth.unwrap_or(SURFACE_DEPTH) as u32
+            })
+            .collect();
+        State {
+            shape: self.next_shape(),
+            jet: self.next_jet,
+            surface,
+        }
+    }
+
+    // Height of the tower once `n` rocks in total have fallen. After enough
+    // rocks the chamber gets back into a state it has been in before, and
+    // from then on every cycle adds the same height, so the whole cycles
+    // are skipped and only the rocks left over are simulated. Rocks that
+    // have already fallen count towards `n`, and the chamber is left as if
+    // all `n` had fallen.
+    pub fn height_after(&mut self, n: u64) -> u64 {
+        let mut seen: HashMap<State, (u64, u64)> = HashMap::new();
+        let mut skipped = false;
+        while self.rocks < n {
+            self.drop_rock();
+            if skipped {
+                continue;
+            }
+            let height = self.height();
+            if let Some((rocks, prev_height)) = seen.insert(self.state(), (self.rocks, height)) {
+                let period = self.rocks - rocks;
+                let cycles = (n - self.rocks) / period;
+                self.rocks += cycles * period;
+                self.skipped_height += cycles * (height - prev_height);
+                skipped = true;
+            }
+        }
+        self.height()
+    }
+
+    // Height of 0 is the floor
+    // 1 means 1 layer of rocks above the floor,
+    // etc...
+    pub fn height(&self) -> u64 {
+        self.skipped_height + self.height as u64
+    }
+}
+
+impl Chamber {
+    // The top `rows` rows of the drawing, which the falling rock is always
+    // in. The floor is only drawn once the whole tower fits.
+    pub fn render_top(&self, rows: usize) -> String {
+        let mut falling = vec![0u8; self.rows.len()];
+        if let Some(rock) = &self.falling {
+            for (y, row) in rock.rows(&self.shapes[rock.shape]) {
+                if y >= falling.len() {
+                    falling.resize(y + 1, 0);
+                }
+                falling[y] |= row;
+            }
+        }
+
+        let mut output_str = String::new();
+        for (y, falling_row) in falling.iter().enumerate().rev().take(rows) {
+            let row = self.rows.get(y).copied().unwrap_or(0);
+            output_str.push('|');
+            for x in 0..self.width {
+                output_str.push(match (row & (1 << x) != 0, falling_row & (1 << x) != 0) {
+                    (true, _) => '#',
+                    (false, true) => '@',
+                    (false, false) => '.',
+                });
+            }
+            output_str.push('|');
+            output_str.push('\n');
+        }
+        if rows > falling.len() {
+            output_str.push('+');
+            for _ in 0..self.width {
+                output_str.push('-');
+            }
+            output_str.push('+');
+            output_str.push('\n');
+        }
+        output_str
+    }
+}
+
+impl fmt::Display for Chamber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render_top(usize::MAX))
+    }
+}
+
+// Rows below the top that are compared when looking for a repeated state.
+// A column open deeper than this is treated as open to this depth, which
+// is safe as long as no rock falls that far past its neighbours.
+const SURFACE_DEPTH: usize = 64;
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+struct State {
+    shape: usize,
+    jet: usize,
+    surface: Vec<u32>,
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    // The jet pattern
+    type Input = Vec<Jet>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        parse_jets(text)
+    }
+
+    // Part 1: height of the tower after 2022 rocks have fallen
+    fn part1(jets: &Self::Input) -> u64 {
+        let mut chamber = Chamber::from_jets(jets.clone(), Config::default());
+
+        for _ in 0..2022 {
+            chamber.drop_rock();
+        }
+
+        chamber.height()
+    }
+
+    // Part 2: height of the tower after 1000000000000 rocks have fallen
+    fn part2(jets: &Self::Input) -> u64 {
+        Chamber::from_jets(jets.clone(), Config::default()).height_after(1_000_000_000_000)
+    }
+}
+
+// Rows of the chamber shown in each frame, enough for a falling rock and
+// the top of the tower under it
+const VISIBLE_ROWS: usize = 30;
+
+impl Visualize for Day17 {
+    // Both parts drop rocks the same way, so both show the first 2022
+    fn visualize(jets: &Self::Input, _part: u8, visualizer: &mut Visualizer) -> io::Result<()> {
+        let mut chamber = Chamber::from_jets(jets.clone(), Config::default());
+
+        for _ in 0..2022 {
+            if visualizer.stopped() {
+                break;
+            }
+            let mut rock = chamber.create_rock();
+            while chamber.move_rock(&mut rock) {
+                visualizer.frame(|| chamber.render_top(VISIBLE_ROWS))?;
+            }
+        }
+        visualizer.finish(|| chamber.render_top(VISIBLE_ROWS))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn custom_shapes() {
+        let shapes = parse_shapes("...\n.#.\n##.\n\n\n#\n").unwrap();
+        assert_eq!(shapes[0], Shape { rows: vec![0b011, 0b010], width: 2 });
+        assert_eq!(shapes[1], Shape { rows: vec![0b1], width: 1 });
+
+        let err = parse_shapes("##\n#o\n").unwrap_err();
+        assert_eq!(err, ParseError::new(2, 2, "`#` or `.`", Some("o")));
+        let err = parse_shapes("#\n\n...\n").unwrap_err();
+        assert_eq!(err, ParseError::new(3, 1, "a shape with at least one `#`", Some("...")));
+    }
+
+    #[test]
+    fn jet_errors() {
+        assert_eq!(parse_jets("<>\n").unwrap(), [Jet::Left, Jet::Right]);
+        assert_eq!(parse_jets("<>\r\n").unwrap_err(), ParseError::new(1, 3, "a jet `<` or `>`", Some("\\r")));
+        assert_eq!(parse_jets("<> <").unwrap_err(), ParseError::new(1, 3, "a jet `<` or `>`", Some(" ")));
+        assert_eq!(parse_jets("<>\n\n").unwrap_err(), ParseError::new(1, 3, "a jet `<` or `>`", Some("\\n")));
+        assert_eq!(parse_jets("\n").unwrap_err(), ParseError::new(1, 1, "a jet `<` or `>`", None));
+    }
+
+    #[test]
+    fn small_chamber() {
+        let config = Config {
+            width: 3,
+            spawn_x: 0,
+            spawn_gap: 1,
+            shapes: parse_shapes("##\n\n#\n#\n").unwrap(),
+        };
+        let mut chamber = Chamber::with_config("><", config).unwrap();
+        for _ in 0..4 {
+            chamber.drop_rock();
+        }
+        assert_eq!(chamber.height(), 6);
+        assert_eq!(chamber.to_string(), "|#..|\n|#..|\n|##.|\n|#..|\n|#..|\n|##.|\n+---+\n");
+        assert_eq!(chamber.render_top(2), "|#..|\n|#..|\n");
+        assert_eq!(chamber.render_top(6), "|#..|\n|#..|\n|##.|\n|#..|\n|#..|\n|##.|\n");
+        assert_eq!(chamber.render_top(7), chamber.to_string());
+
+        // The falling rock is above the tower, so it's in the top rows
+        let mut rock = chamber.create_rock();
+        chamber.move_rock(&mut rock);
+        assert_eq!(chamber.render_top(3), "|.@@|\n|#..|\n|#..|\n");
+    }
+
+    #[test]
+    fn config_errors() {
+        let config = |width, spawn_x, shapes| Config { width, spawn_x, spawn_gap: 3, shapes };
+        let err = Chamber::with_config("<>", config(9, 0, Shape::standard())).unwrap_err();
+        assert_eq!(err, ChamberError::Width(9));
+        let err = Chamber::with_config("<>", config(0, 0, Shape::standard())).unwrap_err();
+        assert_eq!(err, ChamberError::Width(0));
+        let err = Chamber::with_config("<>", config(7, 0, Vec::new())).unwrap_err();
+        assert_eq!(err, ChamberError::NoShapes);
+        let err = Chamber::with_config("<>", config(7, 4, Shape::standard())).unwrap_err();
+        assert_eq!(err, ChamberError::ShapeTooWide { shape: 0, width: 4 });
+        let err = Chamber::with_config("<>", config(3, 8, parse_shapes("##").unwrap())).unwrap_err();
+        assert_eq!(err, ChamberError::ShapeTooWide { shape: 0, width: 2 });
+        assert_eq!(err.to_string(), "shape 0 is 2 units wide and doesn't fit between where rocks appear and the right wall");
+        let err = Chamber::with_config("<x", Config::default()).unwrap_err();
+        assert_eq!(err, ChamberError::Jets(ParseError::new(1, 2, "a jet `<` or `>`", Some("x"))));
+    }
+
+    #[test]
+    fn height_after_twice() {
+        let jets = parse_jets(">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>").unwrap();
+        let mut chamber = Chamber::from_jets(jets.clone(), Config::default());
+        assert_eq!(chamber.height_after(2022), 3068);
+        assert_eq!(chamber.height(), 3068);
+        assert_eq!(chamber.height_after(5000), Chamber::from_jets(jets, Config::default()).height_after(5000));
+        assert_eq!(chamber.height_after(1_000_000_000_000), 1514285714288);
+        assert_eq!(chamber.height(), 1514285714288);
+    }
+}
@@ -0,0 +1,97 @@
+use std::collections::{HashSet, VecDeque};
+use common::{parse, ParseError, Solution};
+use common::geometry::{Bounds3, Point3};
+
+type Point = Point3<i32>;
+
+#[derive(Debug, Clone)]
+pub struct Structure {
+    bounds: Bounds3<i32>,
+    blocks: HashSet<Point>,
+}
+
+impl Structure {
+    fn get_surface_area(&self) -> u32 {
+        let mut surface_area = 0;
+        for block in self.blocks.iter() {
+            let neighbors = block.neighbours6();
+            for neighbor in neighbors {
+                if !self.blocks.contains(&neighbor) {
+                    surface_area += 1;
+                }
+            }
+        }
+        surface_area
+    }
+
+    // Faces reached by flood filling the air from a corner of a box one
+    // step larger than the droplet, so every outside cube is connected
+    fn get_ext_surface_area(&self) -> u32 {
+        let bounds = self.bounds.expand(1);
+        let mut surface_area = 0;
+        let mut visited = HashSet::from([bounds.min]);
+        let mut queue = VecDeque::from([bounds.min]);
+        while let Some(point) = queue.pop_front() {
+            for neighbor in point.neighbours6() {
+                if !bounds.contains(neighbor) {
+                    continue;
+                }
+                if self.blocks.contains(&neighbor) {
+                    surface_area += 1;
+                } else if visited.insert(neighbor) {
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+        surface_area
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    // Cubes making up the lava droplet
+    type Input = Structure;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        read_scan(text)
+    }
+
+    // Part 1: surface area of the lava droplet
+    fn part1(structure: &Self::Input) -> u32 {
+        structure.get_surface_area()
+    }
+
+    // Part 2: surface area reachable from outside the droplet
+    fn part2(structure: &Self::Input) -> u32 {
+        structure.get_ext_surface_area()
+    }
+}
+
+fn read_scan(input_str: &str) -> Result<Structure, ParseError> {
+    let mut blocks = HashSet::new();
+    for line in parse::lines(input_str) {
+        let mut components = line.text.split(',');
+        let mut component = |expected: &str| match components.next() {
+            Some(n) => line.parse::<i32>(n, expected),
+            None => Err(line.error_at_end(expected)),
+        };
+        let point = Point::new(
+            component("an x coordinate")?,
+            component("a y coordinate")?,
+            component("a z coordinate")?,
+        );
+        if let Some(extra) = components.next() {
+            return Err(line.error_at(extra, "end of line"));
+        }
+        blocks.insert(point);
+    }
+    let bounds = Bounds3::from_points(blocks.iter().copied())
+        .ok_or_else(|| parse::unexpected_end(input_str, "a cube like `1,2,3`"))?;
+    Ok(Structure {
+        bounds,
+        blocks,
+    })
+}
@@ -0,0 +1,114 @@
+use common::{parse, ParseError, Solution};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    // Opponent's hand and the unexplained second column of each round
+    type Input = Vec<(HandShape, Column)>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(text).map(parse_line).collect()
+    }
+
+    // Part 1: X, Y, Z are the hand shapes to play
+    fn part1(rounds: &Self::Input) -> u32 {
+        rounds.iter()
+            .map(|(opponent, column)| points_per_line(&decode_1(column), opponent))
+            .sum()
+    }
+
+    // Part 2: X, Y, Z are the outcomes to aim for
+    fn part2(rounds: &Self::Input) -> u32 {
+        rounds.iter()
+            .map(|(opponent, column)| points_per_line(&decode_2(column, opponent), opponent))
+            .sum()
+    }
+}
+
+#[derive(PartialEq)]
+pub enum HandShape {
+    Rock,
+    Paper,
+    Scissors
+}
+
+pub enum Column {
+    X,
+    Y,
+    Z
+}
+
+fn parse_line(line: parse::Line) -> Result<(HandShape, Column), ParseError> {
+    let mut tokens = line.tokens();
+    let opponent = match tokens.next_token("`A`, `B` or `C`")? {
+        "A" => HandShape::Rock,
+        "B" => HandShape::Paper,
+        "C" => HandShape::Scissors,
+        token => return Err(line.error_at(token, "`A`, `B` or `C`"))
+    };
+    let column = match tokens.next_token("`X`, `Y` or `Z`")? {
+        "X" => Column::X,
+        "Y" => Column::Y,
+        "Z" => Column::Z,
+        token => return Err(line.error_at(token, "`X`, `Y` or `Z`"))
+    };
+    tokens.end()?;
+    Ok((opponent, column))
+}
+
+// For part 1 where X,Y,Z are hand shapes
+fn decode_1(column: &Column) -> HandShape {
+    match column {
+        Column::X => HandShape::Rock,
+        Column::Y => HandShape::Paper,
+        Column::Z => HandShape::Scissors
+    }
+}
+
+// For part 2 where X,Y,Z are lose/draw/win
+fn decode_2(column: &Column, opponent: &HandShape) -> HandShape {
+    match column {
+        Column::X => match opponent {
+            HandShape::Rock => HandShape::Scissors,
+            HandShape::Paper => HandShape::Rock,
+            HandShape::Scissors => HandShape::Paper
+        }
+        Column::Y => match opponent {
+            HandShape::Rock => HandShape::Rock,
+            HandShape::Paper => HandShape::Paper,
+            HandShape::Scissors => HandShape::Scissors
+        }
+        Column::Z => match opponent {
+            HandShape::Rock => HandShape::Paper,
+            HandShape::Paper => HandShape::Scissors,
+            HandShape::Scissors => HandShape::Rock
+        }
+    }
+}
+
+fn points_per_line(my_hand: &HandShape, opponent: &HandShape) -> u32 {
+    let mut points = 0;
+    points += match *my_hand {
+        HandShape::Rock =>
+            match *opponent {
+                HandShape::Rock => 4,
+                HandShape::Paper => 1,
+                HandShape::Scissors => 7
+            },
+        HandShape::Paper =>
+            match *opponent {
+                HandShape::Rock => 8,
+                HandShape::Paper => 5,
+                HandShape::Scissors => 2
+            },
+        HandShape::Scissors =>
+            match *opponent {
+                HandShape::Rock => 3,
+                HandShape::Paper => 9,
+                HandShape::Scissors => 6
+            }
+    };
+    points
+}
@@ -0,0 +1,95 @@
+use common::{parse, ParseError, Solution};
+
+// Numbers tagged with their position in the original file,
+// since the same number can appear more than once
+#[derive(Debug, Clone)]
+pub struct CyclicList {
+    data: Vec<(usize, i64)>
+}
+
+impl CyclicList {
+    fn mix(&mut self) {
+        for orig_idx in 0..self.data.len() {
+            let curr_idx = self.data.iter().position(|(i, _)| *i == orig_idx).unwrap();
+            let item = self.data.remove(curr_idx);
+            // Moving past every other number brings it back to where it started
+            let new_idx = curr_idx as i64 + item.1;
+            self.insert(new_idx, item);
+        }
+    }
+
+    fn get(&self, idx: i64) -> Option<i64> {
+        match self.data.len() {
+            0 => None,
+            l => {
+                let idx = idx.rem_euclid(l as i64) as usize;
+                Some(self.data[idx].1)
+            }
+        }
+    }
+
+    fn insert(&mut self, orig_idx: i64, item: (usize, i64)) {
+        let modulus = self.data.len() as i64;
+        let idx = orig_idx.rem_euclid(modulus) as usize;
+        if idx == 0 {
+            self.data.push(item);
+        } else {
+            self.data.insert(idx, item)
+        }
+    }
+
+    fn grove_coordinates(&self) -> i64 {
+        let zero_idx = self.data.iter().position(|(_, n)| *n == 0).unwrap() as i64;
+        let n1 = self.get(zero_idx + 1000).unwrap();
+        let n2 = self.get(zero_idx + 2000).unwrap();
+        let n3 = self.get(zero_idx + 3000).unwrap();
+        n1 + n2 + n3
+    }
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    // The encrypted file
+    type Input = CyclicList;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        parse_input(text)
+    }
+
+    // Part 1: sum of the grove coordinates after mixing once
+    fn part1(data: &Self::Input) -> i64 {
+        let mut data = data.clone();
+        data.mix();
+        data.grove_coordinates()
+    }
+
+    // Part 2: apply the decryption key, then mix ten times
+    fn part2(data: &Self::Input) -> i64 {
+        let mut data = data.clone();
+        for item in data.data.iter_mut() {
+            item.1 *= 811589153;
+        }
+        for _ in 0..10 {
+            data.mix();
+        }
+        data.grove_coordinates()
+    }
+}
+
+fn parse_input(input_str: &str) -> Result<CyclicList, ParseError> {
+    let mut data = Vec::new();
+    for (idx, line) in parse::lines(input_str).enumerate() {
+        data.push((idx, line.parse::<i64>(line.text, "a number")?));
+    }
+    // The grove coordinates are counted from the 0
+    if !data.iter().any(|(_, n)| *n == 0) {
+        return Err(parse::unexpected_end(input_str, "a `0` somewhere in the file"));
+    }
+
+    Ok(CyclicList {
+        data
+    })
+}
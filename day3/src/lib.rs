@@ -0,0 +1,80 @@
+use std::collections::HashSet;
+use common::{parse, ParseError, Solution};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    // Contents of each rucksack
+    type Input = Vec<String>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(text)
+            .map(|line| {
+                line.check_chars(|c| c.is_ascii_alphabetic(), "an item letter")?;
+                if line.text.len() % 2 != 0 {
+                    return Err(line.error_at_end("an even number of items"));
+                }
+                Ok(line.text.to_string())
+            })
+            .collect()
+    }
+
+    // For part 1: find item in common between 2 compartments
+    fn part1(rucksacks: &Self::Input) -> u32 {
+        let mut sum = 0;
+        for line in rucksacks {
+            let length = line.chars().count();
+            assert!(length % 2 == 0);
+            let mut partitions = Vec::new();
+            let part1 = &line[..length/2];
+            partitions.push(part1
+                            .chars()
+                            .collect());
+            let part2 = &line[length/2..];
+            partitions.push(part2
+                            .chars()
+                            .collect());
+            let c = find_common_char(&partitions);
+            sum += char_to_prio(c);
+        }
+        sum
+    }
+
+    // For part 2: find item in common between all 3 elves
+    fn part2(rucksacks: &Self::Input) -> u32 {
+        let mut sum = 0;
+        for group in rucksacks.chunks(3) {
+            let partitions: Vec<HashSet<char>> = group.iter()
+                .map(|line| line.chars().collect())
+                .collect();
+            let c = find_common_char(&partitions);
+            sum += char_to_prio(c);
+        }
+        sum
+    }
+}
+
+fn find_common_char(partitions: &[HashSet<char>]) -> char {
+    assert!(!partitions.is_empty());
+    for c in &partitions[0] {
+        if partitions.iter().all(|p| p.contains(c)) {
+            return *c;
+        }
+    }
+    panic!();
+}
+
+fn char_to_prio(c: char) -> u32 {
+    assert!(c.is_ascii_alphabetic());
+    if c.is_ascii_lowercase() {
+        let c = c as u32;
+        c - 96
+    } else if c.is_ascii_uppercase() {
+        let c = c as u32;
+        c - 38
+    } else {
+        panic!();
+    }
+}
@@ -0,0 +1,44 @@
+use common::{parse, ParseError, Solution};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    // Section range extremes of each pair of elves
+    type Input = Vec<Vec<u32>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(text)
+            .map(|line| {
+                let extremes = line.text
+                    .split(['-', ','])
+                    .map(|n| line.parse::<u32>(n, "a section number"))
+                    .collect::<Result<Vec<u32>, ParseError>>()?;
+                if extremes.len() != 4 {
+                    return Err(line.error_at_end("two section ranges like `2-4,6-8`"));
+                }
+                Ok(extremes)
+            })
+            .collect()
+    }
+
+    // Part 1: how many pairs have one fully containing the other
+    fn part1(pairs: &Self::Input) -> usize {
+        pairs.iter().filter(|x| fully_contains(x)).count()
+    }
+
+    // Part 2: how many pairs have overlapping ranges
+    fn part2(pairs: &Self::Input) -> usize {
+        pairs.iter().filter(|x| overlaps(x)).count()
+    }
+}
+
+fn fully_contains(x: &[u32]) -> bool {
+    x[0] >= x[2] && x[1] <= x[3] || x[0] <= x[2] && x[1] >= x[3]
+}
+
+fn overlaps(x: &[u32]) -> bool {
+    (x[2]..=x[3]).contains(&x[0]) || (x[2]..=x[3]).contains(&x[1])
+        || (x[0]..=x[1]).contains(&x[2]) || (x[0]..=x[1]).contains(&x[3])
+}
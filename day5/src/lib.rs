@@ -0,0 +1,127 @@
+use common::{parse, ParseError, Solution};
+
+pub struct Day5;
+
+// Starting stacks of crates and the rearrangement procedure
+pub struct Procedure {
+    config: Vec<Vec<char>>,
+    moves: Vec<Move>,
+}
+
+struct Move {
+    num: usize,
+    start: usize,
+    dest: usize,
+}
+
+impl Solution for Day5 {
+    type Input = Procedure;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        let lines: Vec<parse::Line> = parse::lines(text).collect();
+        let blank = lines.iter()
+            .position(|line| line.text.is_empty())
+            .ok_or_else(|| parse::unexpected_end(text, "a blank line after the starting stacks"))?;
+
+        // Read initial configuration
+        let config = parse_start_config(&lines[..blank])?;
+
+        // Parse all the instructions
+        let moves = lines[blank + 1..].iter()
+            .map(|line| parse_instruction(line, config.len()))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Procedure { config, moves })
+    }
+
+    // Part 1: crates move 1 at a time
+    fn part1(procedure: &Self::Input) -> String {
+        rearrange(procedure, apply_move_1)
+    }
+
+    // Part 2: crates move all at once
+    fn part2(procedure: &Self::Input) -> String {
+        rearrange(procedure, apply_move_2)
+    }
+}
+
+fn rearrange(procedure: &Procedure, apply: fn(&Move, &mut [Vec<char>])) -> String {
+    let mut config = procedure.config.clone();
+    for m in procedure.moves.iter() {
+        apply(m, &mut config);
+    }
+
+    // Top crate of every stack
+    config.iter().map(|stack| stack.last().unwrap()).collect()
+}
+
+fn parse_start_config(input: &[parse::Line]) -> Result<Vec<Vec<char>>, ParseError> {
+    let mut config: Vec<Vec<char>> = Vec::new();
+    let mut input_lines = input.iter().rev();
+
+    // Consume line with stack number and initialize config
+    let numbers = input_lines.next()
+        .ok_or_else(|| ParseError::new(1, 1, "the starting stacks", None))?;
+    for (i, token) in numbers.tokens().enumerate() {
+        if numbers.parse::<usize>(token, "a stack number")? != i + 1 {
+            return Err(numbers.error_at(token, &format!("stack number {}", i + 1)));
+        }
+        config.push(Vec::new());
+    }
+
+    // Fill config in stack order
+    for line in input_lines {
+        for (i, c) in line.text.chars().enumerate() {
+            if c.is_ascii_alphabetic() {
+                if i/4 >= config.len() {
+                    return Err(ParseError::new(line.number, i + 1, "a crate above a numbered stack", Some(&c.to_string())));
+                }
+                config[i/4].push(c);
+            }
+        }
+    }
+    Ok(config)
+}
+
+fn parse_instruction(line: &parse::Line, num_stacks: usize) -> Result<Move, ParseError> {
+    let mut tokens = line.tokens();
+    tokens.expect("move")?;
+    let num = tokens.parse::<usize>("a number of crates")?;
+    tokens.expect("from")?;
+    let start = parse_stack(line, &mut tokens, num_stacks)?;
+    tokens.expect("to")?;
+    let dest = parse_stack(line, &mut tokens, num_stacks)?;
+    tokens.end()?;
+    Ok(Move {
+        num,
+        start,
+        dest
+    })
+}
+
+fn parse_stack(line: &parse::Line, tokens: &mut parse::Tokens, num_stacks: usize) -> Result<usize, ParseError> {
+    let expected = format!("a stack number from 1 to {}", num_stacks);
+    let token = tokens.next_token(&expected)?;
+    match line.parse::<usize>(token, &expected)? {
+        n if (1..=num_stacks).contains(&n) => Ok(n - 1),
+        _ => Err(line.error_at(token, &expected)),
+    }
+}
+
+// Part 1: crates move 1 at a time
+fn apply_move_1(m: &Move, config: &mut [Vec<char>]) {
+    for _ in 0..m.num {
+        let item = config[m.start].pop().unwrap();
+        config[m.dest].push(item);
+    }
+}
+
+// Part 2: crates move all at once
+fn apply_move_2(m: &Move, config: &mut [Vec<char>]) {
+    let idx = config[m.start].len()-m.num;
+
+    let mut items: Vec<char> = config[m.start].drain(idx..).collect();
+    config[m.dest].append(&mut items);
+}
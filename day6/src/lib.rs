@@ -1,14 +1,31 @@
-use std::fs::File;
-use std::io::{self, read_to_string, BufReader};
 use std::collections::HashMap;
+use common::{parse, ParseError, Solution};
 
-fn main() -> io::Result<()> {
-    let file = File::open("./input.txt")?;
-    let reader = BufReader::new(file);
-    let s = read_to_string(reader)?;
-    println!("Answer: {}", start_marker_index(&s, 14).unwrap());
+pub struct Day6;
 
-    Ok(())
+impl Solution for Day6 {
+    // The datastream buffer
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        let line = parse::lines(text)
+            .next()
+            .ok_or_else(|| parse::unexpected_end(text, "a datastream"))?;
+        line.check_chars(|c| c.is_ascii_lowercase(), "a lowercase letter")?;
+        Ok(line.text.to_string())
+    }
+
+    // Part 1: end of the start-of-packet marker
+    fn part1(s: &Self::Input) -> usize {
+        start_marker_index(s, 4).unwrap()
+    }
+
+    // Part 2: end of the start-of-message marker
+    fn part2(s: &Self::Input) -> usize {
+        start_marker_index(s, 14).unwrap()
+    }
 }
 
 fn start_marker_index(s: &str, size: usize) -> Option<usize> {
@@ -49,5 +66,5 @@ fn start_marker_index(s: &str, size: usize) -> Option<usize> {
         }
 
     }
-    return None;
+    None
 }
@@ -0,0 +1,142 @@
+use common::{parse, ParseError, Solution};
+
+#[allow(dead_code)] // names are only shown in Debug output
+#[derive(Debug)]
+struct File {
+    name: String,
+    size: usize
+}
+
+#[allow(dead_code)] // names are only shown in Debug output
+#[derive(Debug)]
+pub struct Dir {
+    name: String,
+    subdirs: Vec<Dir>,
+    files: Vec<File>
+}
+
+impl Dir {
+    fn new(lines: &[parse::Line]) -> Result<Self, ParseError> {
+        let first = lines.first().ok_or_else(|| ParseError::new(1, 1, "`$ cd /`", None))?;
+        if first.text != "$ cd /" {
+            return Err(first.error_at(first.text, "`$ cd /`"));
+        }
+        Ok(Dir::new_subdir("/", lines, 1)?.0)
+    }
+
+    fn new_subdir(name: &str, lines: &[parse::Line], line_idx: usize) -> Result<(Self, usize), ParseError> {
+        let mut subdirs = Vec::new();
+        let mut files = Vec::new();
+        let mut used_line_count = 0;
+
+        loop {
+            if line_idx + used_line_count >= lines.len() {
+                break Ok((Dir {
+                    name: String::from(name),
+                    subdirs,
+                    files
+                }, used_line_count));
+            }
+            let line = &lines[line_idx + used_line_count];
+            let mut tokens = line.tokens();
+            used_line_count += 1;
+
+            // Check if the line starts with '$'
+            let first_token = tokens.next_token("`$`, `dir` or a file size")?;
+            if first_token == "dir" {
+                continue;
+            } else if first_token == "$" {
+                // Move on to next block
+            } else {
+                let size = line.parse::<usize>(first_token, "`$`, `dir` or a file size")?;
+                files.push(File {
+                    name: String::from(tokens.next_token("a file name")?),
+                    size
+                });
+                continue;
+            }
+
+            match tokens.next_token("`ls` or `cd`")? {
+                "ls" => continue,
+                "cd" => match tokens.next_token("a directory name")? {
+                    ".." => {
+                        return Ok((Dir {
+                            name: String::from(name),
+                            subdirs,
+                            files
+                        }, used_line_count));
+                    }
+                    dest => {
+                        let (d, c) = Dir::new_subdir(dest, lines, line_idx + used_line_count)?;
+                        used_line_count += c;
+                        subdirs.push(d);
+                        continue;
+                    }
+                }
+                token => return Err(line.error_at(token, "`ls` or `cd`"))
+            };
+        }
+    }
+
+    fn find_total_size(&self, limit: u32, acc: &mut u32) -> u32 {
+        let mut total_size = 0;
+        for subdir in &self.subdirs {
+            total_size += subdir.find_total_size(limit, acc)
+        }
+        for file in &self.files {
+            total_size += file.size as u32;
+        }
+        if total_size <= limit {
+            *acc += total_size;
+        }
+        total_size
+    }
+
+    fn find_smallest(&self, lower_limit: u32) -> u32 {
+        let mut total_size = 0;
+        let mut smallest_subdir = u32::MAX;
+        for subdir in &self.subdirs {
+            total_size += subdir.find_total_size(0, &mut 0);
+            match subdir.find_smallest(lower_limit) {
+                size if size < smallest_subdir => smallest_subdir = size,
+                _ => continue
+            }
+        }
+        for file in &self.files {
+            total_size += file.size as u32;
+        }
+        if total_size >= lower_limit && total_size < smallest_subdir {
+            total_size
+        } else {
+            smallest_subdir
+        }
+    }
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    // Filesystem tree rebuilt from the terminal output
+    type Input = Dir;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        let input_lines: Vec<parse::Line> = parse::lines(text).collect();
+        Dir::new(&input_lines)
+    }
+
+    // Part 1: sum of all directories of at most 100000
+    fn part1(fs: &Self::Input) -> u32 {
+        let mut result = 0;
+        fs.find_total_size(100000, &mut result);
+        result
+    }
+
+    // Part 2: smallest directory that frees up enough space for the update
+    fn part2(fs: &Self::Input) -> u32 {
+        let total = fs.find_total_size(0, &mut 0);
+        let limit = 30000000 - (70000000 - total);
+        fs.find_smallest(limit)
+    }
+}
@@ -0,0 +1,50 @@
+use common::{ParseError, Solution};
+use grid::{Grid, Pos, ORTHOGONAL};
+
+// A tree is visible from outside if every tree between it and the edge
+// in some direction is shorter
+fn is_visible(grid: &Grid<u32>, pos: Pos) -> bool {
+    let height = grid[pos];
+    ORTHOGONAL.into_iter().any(|delta| {
+        grid.ray(pos, delta).all(|tree| grid[tree] < height)
+    })
+}
+
+// Product of how far the tree can see in each direction, where a view
+// stops at the edge or at the first tree at least as tall
+fn scenic_score(grid: &Grid<u32>, pos: Pos) -> u32 {
+    let height = grid[pos];
+    ORTHOGONAL.into_iter().map(|delta| {
+        let mut count = 0;
+        for tree in grid.ray(pos, delta) {
+            count += 1;
+            if grid[tree] >= height {
+                break;
+            }
+        }
+        count
+    }).product()
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    // Heights of the trees
+    type Input = Grid<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(text, "a tree height", |c| c.to_digit(10))
+    }
+
+    // Part 1: trees visible from outside the grid
+    fn part1(grid: &Self::Input) -> u32 {
+        grid.positions().filter(|&pos| is_visible(grid, pos)).count() as u32
+    }
+
+    // Part 2: highest scenic score of any tree
+    fn part2(grid: &Self::Input) -> u32 {
+        grid.positions().map(|pos| scenic_score(grid, pos)).max().unwrap_or(0)
+    }
+}
@@ -0,0 +1,129 @@
+use std::io;
+use std::collections::HashSet;
+use common::{parse, ParseError, Solution};
+use common::geometry::{Bounds2, Point2, Vector2};
+use common::visual::{Visualize, Visualizer};
+
+type Vector = Vector2<i32>;
+
+struct Simulation {
+    knots: Vec<Vector>,
+    tail_visited: HashSet<Vector>
+}
+
+impl Simulation {
+    fn new(n: u32) -> Self {
+        Simulation {
+            knots: vec![Vector::zero(); n as usize],
+            tail_visited: HashSet::new()
+        }
+    }
+
+    fn simulate(&mut self, step: Vector) {
+        // First, move head
+        self.knots[0] += step;
+
+        // Calculate where knots need to move
+        for i in 1..self.knots.len() {
+            let diff = self.knots[i-1] - self.knots[i];
+            if diff.chebyshev() > 1 {
+                self.knots[i] += diff.signum();
+            }
+        }
+
+        // Add new position to tail_visited
+        self.tail_visited.insert(self.knots[self.knots.len()-1]);
+    }
+
+    // Knots drawn over the positions the tail has visited, with up at the top
+    fn render(&self) -> String {
+        let points = self.knots.iter().chain(self.tail_visited.iter()).copied();
+        let bounds = Bounds2::from_points(points.chain([Vector::zero()])).unwrap().expand(1);
+
+        let mut output = String::new();
+        for y in (bounds.min.y..=bounds.max.y).rev() {
+            for x in bounds.min.x..=bounds.max.x {
+                let p = Point2::new(x, y);
+                let c = match self.knots.iter().position(|&knot| knot == p) {
+                    Some(0) => 'H',
+                    Some(i) if i == self.knots.len() - 1 => 'T',
+                    Some(i) => char::from_digit(i as u32, 10).unwrap_or('*'),
+                    None if p == Vector::zero() => 's',
+                    None if self.tail_visited.contains(&p) => '#',
+                    None => '.',
+                };
+                output.push(c);
+            }
+            output.push('\n');
+        }
+        output
+    }
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    // Single-unit moves of the head
+    type Input = Vec<Vector>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        get_step_list(text)
+    }
+
+    // Part 1: positions visited by the tail of a two-knot rope
+    fn part1(steps: &Self::Input) -> usize {
+        count_tail_positions(steps, 2)
+    }
+
+    // Part 2: positions visited by the tail of a ten-knot rope
+    fn part2(steps: &Self::Input) -> usize {
+        count_tail_positions(steps, 10)
+    }
+}
+
+impl Visualize for Day9 {
+    fn visualize(steps: &Self::Input, part: u8, visualizer: &mut Visualizer) -> io::Result<()> {
+        let mut sim = Simulation::new(if part == 1 { 2 } else { 10 });
+        for step in steps {
+            if visualizer.stopped() {
+                break;
+            }
+            sim.simulate(*step);
+            visualizer.frame(|| sim.render())?;
+        }
+        visualizer.finish(|| sim.render())
+    }
+}
+
+fn count_tail_positions(steps: &[Vector], knots: u32) -> usize {
+    let mut sim = Simulation::new(knots);
+
+    for step in steps {
+        sim.simulate(*step);
+    }
+
+    sim.tail_visited.len()
+}
+
+fn get_step_list(input: &str) -> Result<Vec<Vector>, ParseError> {
+    let mut steps = Vec::new();
+    for line in parse::lines(input) {
+        let mut tokens = line.tokens();
+        let direction = tokens.next_token("`L`, `R`, `U` or `D`")?;
+        let step = match direction {
+            "L" => Vector::new(-1, 0),
+            "R" => Vector::new(1, 0),
+            "U" => Vector::new(0, 1),
+            "D" => Vector::new(0, -1),
+            _ => return Err(line.error_at(direction, "`L`, `R`, `U` or `D`"))
+        };
+        let count = tokens.parse::<u32>("a number of steps")?;
+        tokens.end()?;
+        for _ in 0..count {
+            steps.push(step);
+        }
+    }
+    Ok(steps)
+}
@@ -0,0 +1,209 @@
+use std::ops::{Index, IndexMut};
+use common::{parse, ParseError};
+
+mod offset;
+
+pub use offset::OffsetGrid;
+
+// Position of a cell as (x, y), with (0, 0) in the first row
+pub type Pos = (usize, usize);
+
+// Steps to the four cells sharing an edge
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+// Steps to the eight cells sharing an edge or a corner
+pub const ADJACENT: [(isize, isize); 8] = [
+    (0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1),
+];
+
+// Rectangle of cells stored row by row in one Vec
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        assert!(width > 0 && cells.len().is_multiple_of(width), "{} cells don't make rows of {}", cells.len(), width);
+        Grid {
+            width,
+            height: cells.len() / width,
+            cells,
+        }
+    }
+
+    // Read a grid with one character per cell. Every line has to be as wide
+    // as the first, and `cell` turns down characters that aren't `expected`.
+    pub fn parse(text: &str, expected: &str, cell: impl Fn(char) -> Option<T>) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        for line in parse::lines(text) {
+            let mut count = 0;
+            for (idx, c) in line.text.char_indices() {
+                let part = &line.text[idx..idx + c.len_utf8()];
+                if width == Some(count) {
+                    return Err(line.error_at(&line.text[idx..], "end of line"));
+                }
+                cells.push(cell(c).ok_or_else(|| line.error_at(part, expected))?);
+                count += 1;
+            }
+            match width {
+                Some(width) if count < width => return Err(line.error_at_end(expected)),
+                Some(_) => (),
+                None if count == 0 => return Err(line.error_at_end(expected)),
+                None => width = Some(count),
+            }
+        }
+        let width = width.ok_or_else(|| parse::unexpected_end(text, expected))?;
+        Ok(Grid::from_cells(width, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): Pos) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (x, y): Pos) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    // Position one step away, if it is still inside the grid
+    pub fn step(&self, (x, y): Pos, (dx, dy): (isize, isize)) -> Option<Pos> {
+        let x = x.checked_add_signed(dx).filter(|&x| x < self.width)?;
+        let y = y.checked_add_signed(dy).filter(|&y| y < self.height)?;
+        Some((x, y))
+    }
+
+    // Add a row after the last one
+    pub fn push_row(&mut self, row: impl IntoIterator<Item = T>) {
+        let len = self.cells.len();
+        self.cells.extend(row);
+        assert_eq!(self.cells.len() - len, self.width, "row doesn't fit a grid {} wide", self.width);
+        self.height += 1;
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        // chunks() can't have a size of 0
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "column {} is outside a grid {} wide", x, self.width);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    // Cells sharing an edge with `pos`
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ORTHOGONAL.into_iter().filter_map(move |delta| self.step(pos, delta))
+    }
+
+    // Cells sharing an edge or a corner with `pos`
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ADJACENT.into_iter().filter_map(move |delta| self.step(pos, delta))
+    }
+
+    // Cells from `pos` to the edge of the grid in steps of `delta`, not
+    // including `pos` itself
+    pub fn ray(&self, pos: Pos, delta: (isize, isize)) -> impl Iterator<Item = Pos> + '_ {
+        std::iter::successors(Some(pos), move |&pos| self.step(pos, delta)).skip(1)
+    }
+
+    // One line per row with one character per cell
+    pub fn render(&self, cell: impl Fn(&T) -> char) -> String {
+        let mut output = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            output.extend(row.iter().map(&cell));
+            output.push('\n');
+        }
+        output
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside a {}x{} grid", pos, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside a {}x{} grid", pos, width, height))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(text: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(text, "a digit", |c| c.to_digit(10))
+    }
+
+    #[test]
+    fn parse_and_render() {
+        let grid = digits("123\n456\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [2, 5]);
+        assert_eq!(grid.render(|d| char::from_digit(*d, 10).unwrap()), "123\n456\n");
+
+        assert_eq!(digits("123\n4x6").unwrap_err(), ParseError::new(2, 2, "a digit", Some("x")));
+        assert_eq!(digits("123\n45").unwrap_err(), ParseError::new(2, 3, "a digit", None));
+        assert_eq!(digits("123\n4567").unwrap_err(), ParseError::new(2, 4, "end of line", Some("7")));
+        assert_eq!(digits("").unwrap_err(), ParseError::new(1, 1, "a digit", None));
+    }
+
+    #[test]
+    fn neighbours_and_rays() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(grid.neighbours4((0, 0)).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbours8((2, 2)).count(), 3);
+        assert_eq!(grid.ray((0, 1), (1, 0)).collect::<Vec<_>>(), [(1, 1), (2, 1)]);
+        assert_eq!(grid.ray((0, 1), (-1, 0)).count(), 0);
+    }
+}
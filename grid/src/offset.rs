@@ -0,0 +1,108 @@
+use std::ops::RangeInclusive;
+use crate::Grid;
+
+// Grid addressed by signed coordinates that grows to fit whatever is set.
+// Cells that were never set read as `fill`.
+#[derive(Debug, Clone)]
+pub struct OffsetGrid<T> {
+    grid: Grid<T>,
+    // Coordinates of the grid's (0, 0) cell
+    origin: (isize, isize),
+    fill: T,
+}
+
+impl<T: Clone> OffsetGrid<T> {
+    pub fn new(fill: T) -> Self {
+        OffsetGrid {
+            grid: Grid::new(0, 0, fill.clone()),
+            origin: (0, 0),
+            fill,
+        }
+    }
+
+    fn local(&self, (x, y): (isize, isize)) -> Option<(usize, usize)> {
+        let x = usize::try_from(x - self.origin.0).ok()?;
+        let y = usize::try_from(y - self.origin.1).ok()?;
+        (x < self.grid.width() && y < self.grid.height()).then_some((x, y))
+    }
+
+    pub fn get(&self, pos: (isize, isize)) -> &T {
+        match self.local(pos) {
+            Some(local) => &self.grid[local],
+            None => &self.fill,
+        }
+    }
+
+    pub fn set(&mut self, pos: (isize, isize), value: T) {
+        if self.local(pos).is_none() {
+            self.grow(pos);
+        }
+        let local = self.local(pos).unwrap();
+        self.grid[local] = value;
+    }
+
+    // Resize to take in `pos`, leaving some room around it so a grid that
+    // keeps growing the same way isn't copied for every new cell
+    fn grow(&mut self, (x, y): (isize, isize)) {
+        let (old_x, old_y) = (self.x_range(), self.y_range());
+        let (x_min, x_max, y_min, y_max) = if self.grid.width() == 0 {
+            (x, x, y, y)
+        } else {
+            let x_slack = (self.grid.width() / 2) as isize;
+            let y_slack = (self.grid.height() / 2) as isize;
+            (
+                if x < *old_x.start() { x - x_slack } else { *old_x.start() },
+                if x > *old_x.end() { x + x_slack } else { *old_x.end() },
+                if y < *old_y.start() { y - y_slack } else { *old_y.start() },
+                if y > *old_y.end() { y + y_slack } else { *old_y.end() },
+            )
+        };
+
+        let width = (x_max - x_min + 1) as usize;
+        let height = (y_max - y_min + 1) as usize;
+        let mut grid = Grid::new(width, height, self.fill.clone());
+        for ((x, y), cell) in self.grid.iter() {
+            let x = x as isize + self.origin.0 - x_min;
+            let y = y as isize + self.origin.1 - y_min;
+            grid[(x as usize, y as usize)] = cell.clone();
+        }
+        self.grid = grid;
+        self.origin = (x_min, y_min);
+    }
+}
+
+impl<T> OffsetGrid<T> {
+    // Columns currently stored, which may be more than were ever set
+    pub fn x_range(&self) -> RangeInclusive<isize> {
+        self.origin.0..=self.origin.0 + self.grid.width() as isize - 1
+    }
+
+    // Rows currently stored, which may be more than were ever set
+    pub fn y_range(&self) -> RangeInclusive<isize> {
+        self.origin.1..=self.origin.1 + self.grid.height() as isize - 1
+    }
+
+    pub fn render(&self, cell: impl Fn(&T) -> char) -> String {
+        self.grid.render(cell)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grows_in_every_direction() {
+        let mut grid = OffsetGrid::new('.');
+        grid.set((500, 0), '+');
+        grid.set((498, 4), '#');
+        grid.set((503, -1), '#');
+        assert_eq!(*grid.get((500, 0)), '+');
+        assert_eq!(*grid.get((498, 4)), '#');
+        assert_eq!(*grid.get((503, -1)), '#');
+        assert_eq!(*grid.get((499, 4)), '.');
+        assert_eq!(*grid.get((-7, 1000)), '.');
+        assert!(grid.x_range().contains(&498) && grid.x_range().contains(&503));
+        assert!(grid.y_range().contains(&-1) && grid.y_range().contains(&4));
+    }
+}